@@ -18,4 +18,115 @@ pub enum ProtocolError {
     UsernameTooLong,
     #[error("Incorrect validation account was provided")]
     IncorrectValidationAccount,
+    #[error("Incorrect protocol config account")]
+    IncorrectProtocolConfig,
+    #[error("Signer is not the protocol admin")]
+    IncorrectAdmin,
+    #[error("Source profile is frozen")]
+    SourceProfileFrozen,
+    #[error("Destination profile is frozen")]
+    DestinationProfileFrozen,
+    #[error("Incorrect denylist account")]
+    IncorrectDenylist,
+    #[error("Wallet is already on the denylist")]
+    WalletAlreadyDenylisted,
+    #[error("Wallet is not on the denylist")]
+    WalletNotDenylisted,
+    #[error("Source owner is on the denylist")]
+    SourceOwnerDenylisted,
+    #[error("Destination owner is on the denylist")]
+    DestinationOwnerDenylisted,
+    #[error("Signer is not the mint authority")]
+    IncorrectMintAuthority,
+    #[error("Incorrect exemptions account")]
+    IncorrectExemptionsAccount,
+    #[error("Owner is already exempt")]
+    OwnerAlreadyExempt,
+    #[error("Owner is not exempt")]
+    OwnerNotExempt,
+    #[error("Incorrect wallet link account")]
+    IncorrectWalletLink,
+    #[error("Wallet is already linked")]
+    WalletAlreadyLinked,
+    #[error("Wallet has its own profile")]
+    WalletHasProfile,
+    #[error("Recovery wallet must differ from the profile's wallet")]
+    RecoveryWalletUnchanged,
+    #[error("Incorrect mint rules account")]
+    IncorrectMintRules,
+    #[error("Transfer amount is below the mint's minimum")]
+    TransferBelowMinimum,
+    #[error("Transfer amount is above the mint's maximum")]
+    TransferAboveMaximum,
+    #[error("Transfer exceeds the source owner's outflow limit for the mint")]
+    OutflowLimitExceeded,
+    #[error("Transfer would push the destination above the mint's maximum balance")]
+    DestinationAboveMaximumBalance,
+    #[error("Incorrect lockup account")]
+    IncorrectLockup,
+    #[error("Unlock schedule ends before it starts")]
+    InvalidUnlockSchedule,
+    #[error("Transfer would move tokens that are still locked")]
+    TransferExceedsUnlockedBalance,
+    #[error("Source profile's transfer cooldown has not elapsed")]
+    TransferCooldownActive,
+    #[error("Incorrect trading schedule account")]
+    IncorrectTradingSchedule,
+    #[error("Trading is closed for this mint")]
+    TradingClosed,
+    #[error("Delegate is on the protocol denylist")]
+    DelegateDenylisted,
+    #[error("Delegate profile is frozen")]
+    DelegateProfileFrozen,
+    #[error("Incorrect pair volume account")]
+    IncorrectPairVolume,
+    #[error("Pair volume accounts must be initialized for this mint")]
+    PairVolumeNotInitialized,
+    #[error("Profile is flagged for wash trading")]
+    WashTradingFlagged,
+    #[error("Signer is not the mint's permit approver")]
+    IncorrectPermitApprover,
+    #[error("Incorrect transfer permit account")]
+    IncorrectTransferPermit,
+    #[error("Transfer amount requires a transfer permit")]
+    TransferPermitRequired,
+    #[error("Transfer amount exceeds the transfer permit")]
+    TransferPermitExceeded,
+    #[error("Transfer permit has expired")]
+    TransferPermitExpired,
+    #[error("Mint is halted by its circuit breaker")]
+    MintHalted,
+    #[error("Incorrect order tracker account")]
+    IncorrectOrderTracker,
+    #[error("Transfer amount requires a memo")]
+    MemoRequired,
+    #[error("Incorrect instructions sysvar")]
+    IncorrectInstructionsSysvar,
+    // No longer returned, but kept so later error codes don't shift.
+    #[error("Incorrect transfer record account")]
+    IncorrectTransferRecord,
+    #[error("Incorrect program filter account")]
+    IncorrectProgramFilter,
+    #[error("Program is not permitted to transfer this mint")]
+    ProgramNotPermitted,
+    #[error("Credential mint must be a non-transferable Token-2022 mint")]
+    IncorrectCredentialMint,
+    #[error("Account belongs to a different namespace")]
+    IncorrectNamespace,
+    #[error("Incorrect transfer history account")]
+    IncorrectTransferHistory,
+    #[error("Signer is not the program's upgrade authority")]
+    IncorrectUpgradeAuthority,
+    #[error("Every wallet linked to the profile must be provided")]
+    LinkedWalletsMissing,
+    #[error("Incorrect mint activity account")]
+    IncorrectMintActivity,
+    #[error("Mint activity account must be initialized for this mint")]
+    MintActivityNotInitialized,
+    #[error("While tokens are locked, the source must be the owner's associated token account")]
+    LockedSourceNotAssociatedTokenAccount,
+    #[error("Wallet link not initialized")]
+    WalletLinkNotInitialized,
+    #[error("Profile is frozen")]
+    ProfileFrozen,
 }
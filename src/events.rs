@@ -0,0 +1,150 @@
+//! Events emitted by the protocol's processors, so indexers can follow state
+//! changes without diffing accounts.
+//!
+//! Each event is written to the program logs with `sol_log_data` as a single
+//! borsh-encoded `VersionedEvent`.
+
+use {
+    crate::state::{ProgramFilterMode, TradingOverride},
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{log::sol_log_data, pubkey::Pubkey},
+};
+
+/// The volume totals after a transfer is recorded.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, Default, PartialEq)]
+pub struct VolumeTotals {
+    /// The mint's order volume in the order tracker, or zero if the mint does
+    /// not track volume.
+    pub mint_volume: u64,
+    /// The order volume of the profile the transfer was recorded on, or zero
+    /// if neither owner has a profile.
+    pub order_volume: u64,
+    /// The self-transfer volume of the profile the transfer was recorded on,
+    /// or zero if neither owner has a profile.
+    pub self_transfer_volume: u64,
+}
+
+/// An event emitted by the protocol.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum ProtocolEvent {
+    /// The protocol was initialized within a namespace.
+    ProtocolInitialized { namespace: Pubkey, admin: Pubkey },
+    /// A protocol mint was created.
+    MintCreated {
+        mint: Pubkey,
+        mint_authority: Pubkey,
+        namespace: Pubkey,
+    },
+    /// A profile was initialized and its soulbound token minted.
+    ProfileInitialized { namespace: Pubkey, wallet: Pubkey },
+    /// A profile was frozen by the protocol admin.
+    ProfileFrozen { namespace: Pubkey, wallet: Pubkey },
+    /// A profile was thawed by the protocol admin.
+    ProfileThawed { namespace: Pubkey, wallet: Pubkey },
+    /// A wallet was added to a namespace's denylist.
+    WalletDenylisted { namespace: Pubkey, wallet: Pubkey },
+    /// A wallet was removed from a namespace's denylist.
+    WalletUndenylisted { namespace: Pubkey, wallet: Pubkey },
+    /// A token account owner was exempted for a protocol mint.
+    ExemptionAdded { mint: Pubkey, owner: Pubkey },
+    /// A token account owner's exemption for a protocol mint was removed.
+    ExemptionRemoved { mint: Pubkey, owner: Pubkey },
+    /// A secondary wallet was linked to a primary wallet's profile.
+    WalletLinked {
+        namespace: Pubkey,
+        primary_wallet: Pubkey,
+        secondary_wallet: Pubkey,
+    },
+    /// A profile was moved from a lost wallet to a new wallet.
+    ProfileRecovered {
+        namespace: Pubkey,
+        old_wallet: Pubkey,
+        new_wallet: Pubkey,
+    },
+    /// A protocol mint's transfer rules were replaced.
+    MintRulesUpdated { mint: Pubkey },
+    /// A profile's outflow limit for a protocol mint was set, keyed by its
+    /// primary wallet.
+    OutflowLimitSet {
+        namespace: Pubkey,
+        mint: Pubkey,
+        wallet: Pubkey,
+        outflow_limit: u64,
+    },
+    /// A wallet's lockup on a protocol mint was set.
+    LockupSet { mint: Pubkey, wallet: Pubkey },
+    /// A protocol mint's trading windows were replaced.
+    TradingWindowsSet { mint: Pubkey },
+    /// A protocol mint's trading override was set.
+    TradingOverrideSet {
+        mint: Pubkey,
+        trading_override: TradingOverride,
+    },
+    /// The pair volume accounts between two primary wallets were initialized.
+    PairVolumeInitialized {
+        mint: Pubkey,
+        first_wallet: Pubkey,
+        second_wallet: Pubkey,
+    },
+    /// A profile's wash trading flag was cleared.
+    WashTradingFlagCleared { namespace: Pubkey, wallet: Pubkey },
+    /// A transfer permit was issued.
+    TransferPermitIssued {
+        mint: Pubkey,
+        source: Pubkey,
+        destination: Pubkey,
+        max_amount: u64,
+        expiry: i64,
+    },
+    /// A protocol mint's circuit breaker halt was lifted.
+    CircuitBreakerReset { namespace: Pubkey, mint: Pubkey },
+    /// A protocol mint's program filter was replaced.
+    ProgramFilterSet {
+        mint: Pubkey,
+        mode: ProgramFilterMode,
+        program_ids: Vec<Pubkey>,
+    },
+    /// A token account owner was exempted from a protocol mint's maximum
+    /// balance.
+    BalanceCapExemptionAdded { mint: Pubkey, owner: Pubkey },
+    /// A token account owner's exemption from a protocol mint's maximum
+    /// balance was removed.
+    BalanceCapExemptionRemoved { mint: Pubkey, owner: Pubkey },
+    /// A wallet's mint activity account was initialized.
+    MintActivityInitialized { mint: Pubkey, wallet: Pubkey },
+    /// A wallet without a profile was linked to itself.
+    WalletLinkInitialized { namespace: Pubkey, wallet: Pubkey },
+    /// A protocol mint's circuit breaker was set, or removed if its threshold
+    /// is zero.
+    CircuitBreakerSet {
+        namespace: Pubkey,
+        mint: Pubkey,
+        threshold: u64,
+        window_slots: u64,
+    },
+    /// A transfer of a protocol mint was recorded.
+    TransferRecorded {
+        mint: Pubkey,
+        source: Pubkey,
+        destination: Pubkey,
+        source_owner: Pubkey,
+        destination_owner: Pubkey,
+        amount: u64,
+        new_totals: VolumeTotals,
+    },
+}
+
+impl ProtocolEvent {
+    /// Writes the event to the program logs as the current event version.
+    pub fn emit(self) {
+        let data = VersionedEvent::V1(self).try_to_vec().unwrap();
+        sol_log_data(&[&data]);
+    }
+}
+
+/// A protocol event, tagged with the version of its encoding. New event
+/// versions are added as new variants, so older events remain decodable.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum VersionedEvent {
+    V1(ProtocolEvent),
+}
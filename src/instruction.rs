@@ -1,4 +1,5 @@
 use {
+    crate::state::{Lockup, MintRules, ProgramFilterMode, TradingOverride, TradingWindow},
     borsh::{BorshDeserialize, BorshSerialize},
     solana_program::{
         instruction::{AccountMeta, Instruction},
@@ -8,12 +9,18 @@ use {
     spl_transfer_hook_interface::get_extra_account_metas_address,
 };
 
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct NamespaceInstruction {
+    pub namespace: Pubkey,
+}
+
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct CreateMintInstruction {
     pub decimals: u8,
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    pub rules: MintRules,
 }
 
 #[derive(BorshDeserialize, BorshSerialize)]
@@ -21,25 +28,88 @@ pub struct InitializeProfileInstruction {
     pub username: String,
 }
 
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct UpdateDenylistInstruction {
+    pub wallet_address: Pubkey,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct UpdateExemptionsInstruction {
+    pub owner_address: Pubkey,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct SetOutflowLimitInstruction {
+    pub outflow_limit: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct SetCircuitBreakerInstruction {
+    pub threshold: u64,
+    pub window_slots: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct SetTradingWindowsInstruction {
+    pub windows: Vec<TradingWindow>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct SetTradingOverrideInstruction {
+    pub trading_override: TradingOverride,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct IssueTransferPermitInstruction {
+    pub max_amount: u64,
+    pub expiry: i64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct SetProgramFilterInstruction {
+    pub mode: ProgramFilterMode,
+    pub program_ids: Vec<Pubkey>,
+}
+
 pub enum ProtocolInstruction {
-    /// Initializes the protocol.
+    /// Initializes the protocol within a namespace, with the payer as the
+    /// namespace's admin.
+    ///
+    /// A namespace is claimed by signing with its key. The default namespace
+    /// has no key, so it may only be claimed by the program's upgrade
+    /// authority.
     ///
     /// Accounts expected by this instruction:
     ///
     /// 0. [w]   Soulbound Mint
     /// 1. [w]   Order Tracker
-    /// 2. [w+s] Payer
-    /// 3. []    Token-2022 Program
-    InitializeProtocol,
-    /// Creates a new protocol mint.
+    /// 2. [w]   Protocol Config
+    /// 3. [w]   Denylist
+    /// 4. [w+s] Payer (Protocol Admin)
+    /// 5. []    Token-2022 Program
+    /// 6. []    System Program
+    /// 7. [s]   Namespace (the program's data account for the default one)
+    /// 8. [w]   Soulbound Mint Authority
+    InitializeProtocol(NamespaceInstruction),
+    /// Creates a new protocol mint in the namespace named by its rules, with
+    /// the namespace's admin's approval.
     ///
     /// Accounts expected by this instruction:
     /// 0. [w]   Mint
     /// 1. [w]   Validation Account
-    /// 2. [s]   Mint Authority
+    /// 2. [w+s] Mint Authority
     /// 3. []    Token-2022 Program
+    /// 4. [w]   Exemptions
+    /// 5. []    System Program
+    /// 6. [w]   Mint Rules
+    /// 7. []    Credential Mint
+    /// 8. [w]   Transfer History
+    /// 9. []    Protocol Config
+    /// 10. [s]  Protocol Admin
+    /// 11. [w]  Order Tracker
     CreateMint(CreateMintInstruction),
-    /// Initializes a profile for a user and mints a soulbound token.
+    /// Initializes a profile for a user within a namespace and mints the
+    /// namespace's soulbound token.
     ///
     /// Accounts expected by this instruction:
     /// 0. [w]   Soulbound Mint
@@ -49,22 +119,349 @@ pub enum ProtocolInstruction {
     /// 4. []    Souldbound Mint Authority
     /// 5. []    Token-2022 Program
     /// 6. []    System Program
-    InitializeProfile,
+    /// 7. [w]   User's Wallet Link
+    InitializeProfile(NamespaceInstruction),
+    /// Freezes a user's profile, blocking transfers to and from the user.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Protocol Config
+    /// 1. [s]   Protocol Admin
+    /// 2. [w]   User's Profile
+    FreezeProfile,
+    /// Thaws a user's frozen profile.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Protocol Config
+    /// 1. [s]   Protocol Admin
+    /// 2. [w]   User's Profile
+    ThawProfile,
+    /// Adds a wallet to the protocol's denylist.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Protocol Config
+    /// 1. [w+s] Protocol Admin
+    /// 2. [w]   Denylist
+    /// 3. []    System Program
+    AddToDenylist(UpdateDenylistInstruction),
+    /// Removes a wallet from the protocol's denylist.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Protocol Config
+    /// 1. [w+s] Protocol Admin
+    /// 2. [w]   Denylist
+    /// 3. []    System Program
+    RemoveFromDenylist(UpdateDenylistInstruction),
+    /// Exempts a token account owner from the soulbound and profile
+    /// requirements for a protocol mint.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Mint
+    /// 1. [w+s] Mint Authority
+    /// 2. [w]   Exemptions
+    /// 3. []    System Program
+    AddExemption(UpdateExemptionsInstruction),
+    /// Removes a token account owner's exemption for a protocol mint.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Mint
+    /// 1. [w+s] Mint Authority
+    /// 2. [w]   Exemptions
+    /// 3. []    System Program
+    RemoveExemption(UpdateExemptionsInstruction),
+    /// Links a secondary wallet to a primary wallet's profile within a
+    /// namespace, so the secondary wallet trades under the primary wallet's
+    /// credential and profile. The secondary wallet may not have a profile of
+    /// its own, and a frozen or flagged profile may not link new wallets.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. [w]   Primary Profile
+    /// 1. [w+s] Primary Wallet
+    /// 2. []    Secondary Profile
+    /// 3. [s]   Secondary Wallet
+    /// 4. [w]   Secondary Wallet Link
+    /// 5. []    System Program
+    /// 6. []    Primary Wallet Link
+    LinkWallet(NamespaceInstruction),
+    /// Moves a profile from a lost wallet to a new wallet, approved by the
+    /// protocol admin. The old wallet's soulbound token is burned, the new
+    /// wallet is minted a soulbound token, and the old profile is closed.
+    /// Every secondary wallet linked to the profile must be provided, and is
+    /// linked to the new wallet.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Protocol Config
+    /// 1. [w+s] Protocol Admin
+    /// 2. [w]   Soulbound Mint
+    /// 3. []    Soulbound Mint Authority
+    /// 4. [w]   Old Wallet's Soulbound Token Account
+    /// 5. [w]   Old Profile
+    /// 6. [w]   Old Wallet Link
+    /// 7. []    Old Wallet
+    /// 8. [w]   New Wallet's Soulbound Token Account
+    /// 9. [w]   New Profile
+    /// 10. [w]  New Wallet Link
+    /// 11. [s]  New Wallet
+    /// 12. []   Token-2022 Program
+    /// 13. []   System Program
+    ///
+    /// Followed by a `[]` Linked Wallet and `[w]` Linked Wallet's Link pair
+    /// for each secondary wallet linked to the profile.
+    RecoverProfile,
+    /// Replaces a protocol mint's transfer rules.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Mint
+    /// 1. [s]   Mint Authority
+    /// 2. [w]   Mint Rules
+    /// 3. []    Credential Mint
+    UpdateMintRules(MintRules),
+    /// Sets the maximum amount of a protocol mint a profile may send within a
+    /// sliding 24-hour window, across its primary wallet and every wallet
+    /// linked to it. A limit of zero removes the limit. The profile's mint
+    /// activity account is created if it does not exist.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Protocol Config
+    /// 1. [w+s] Protocol Admin
+    /// 2. [w]   Mint Activity
+    /// 3. []    Primary Wallet
+    /// 4. []    Mint
+    /// 5. []    Mint Rules
+    /// 6. []    System Program
+    /// 7. []    Profile
+    SetOutflowLimit(SetOutflowLimitInstruction),
+    /// Sets or replaces the lockup on a wallet's tokens of a protocol mint.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Mint
+    /// 1. [w+s] Mint Authority
+    /// 2. [w]   Lockup
+    /// 3. []    Wallet
+    /// 4. []    System Program
+    SetLockup(Lockup),
+    /// Replaces the UTC windows during which a protocol mint may be
+    /// transferred.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Mint
+    /// 1. [w+s] Mint Authority
+    /// 2. [w]   Trading Schedule
+    /// 3. []    System Program
+    SetTradingWindows(SetTradingWindowsInstruction),
+    /// Opens or closes trading of a protocol mint regardless of its windows,
+    /// or returns it to following its windows.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Protocol Config
+    /// 1. [w+s] Protocol Admin
+    /// 2. []    Mint
+    /// 3. [w]   Trading Schedule
+    /// 4. []    System Program
+    /// 5. []    Mint Rules
+    SetTradingOverride(SetTradingOverrideInstruction),
+    /// Initializes the pair volume accounts, in both directions, between two
+    /// primary wallets for a protocol mint. While the mint's wash trading
+    /// detection is enabled, transfers between the two wallets' profiles
+    /// require these accounts. Does nothing for accounts that already exist.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. [w+s] Payer
+    /// 1. []    Mint
+    /// 2. []    First Wallet
+    /// 3. []    Second Wallet
+    /// 4. [w]   Pair Volume (First to Second)
+    /// 5. [w]   Pair Volume (Second to First)
+    /// 6. []    System Program
+    InitializePairVolume,
+    /// Clears a user's wash trading flag.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Protocol Config
+    /// 1. [s]   Protocol Admin
+    /// 2. [w]   User's Profile
+    ClearWashTradingFlag,
+    /// Issues a permit for a transfer between two token accounts of a
+    /// protocol mint, replacing any earlier permit between them.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Mint
+    /// 1. []    Mint Rules
+    /// 2. [w+s] Permit Approver
+    /// 3. []    Source Token Account
+    /// 4. []    Destination Token Account
+    /// 5. [w]   Transfer Permit
+    /// 6. []    System Program
+    IssueTransferPermit(IssueTransferPermitInstruction),
+    /// Lifts a protocol mint's circuit breaker halt.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Protocol Config
+    /// 1. [s]   Protocol Admin
+    /// 2. [w]   Order Tracker
+    /// 3. []    Mint
+    ResetCircuitBreaker,
+    /// Replaces the top-level programs permitted to initiate transfers of a
+    /// protocol mint.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Mint
+    /// 1. [w+s] Mint Authority
+    /// 2. [w]   Program Filter
+    /// 3. []    System Program
+    SetProgramFilter(SetProgramFilterInstruction),
+    /// Initializes a profile's activity account for a protocol mint, keyed by
+    /// its primary wallet, which the profile's wallets need to send the mint
+    /// while it has a transfer cooldown.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. [w+s] Payer
+    /// 1. []    Mint
+    /// 2. []    Primary Wallet
+    /// 3. [w]   Mint Activity
+    /// 4. []    System Program
+    InitializeMintActivity,
+    /// Exempts a token account owner, such as a treasury, from a protocol
+    /// mint's maximum balance.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Mint
+    /// 1. [w+s] Mint Authority
+    /// 2. [w]   Exemptions
+    /// 3. []    System Program
+    AddBalanceCapExemption(UpdateExemptionsInstruction),
+    /// Removes a token account owner's exemption from a protocol mint's
+    /// maximum balance.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Mint
+    /// 1. [w+s] Mint Authority
+    /// 2. [w]   Exemptions
+    /// 3. []    System Program
+    RemoveBalanceCapExemption(UpdateExemptionsInstruction),
+    /// Links a wallet without a profile, such as a pool authority, to itself
+    /// within a namespace, so it can hold and receive protocol mints. Does
+    /// nothing if the wallet is already linked.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. [w+s] Payer
+    /// 1. []    Wallet
+    /// 2. [w]   Wallet Link
+    /// 3. []    System Program
+    InitializeWalletLink(NamespaceInstruction),
+    /// Sets the volume within a window of slots above which a protocol mint
+    /// is halted until the protocol admin resets it. A zero threshold removes
+    /// the mint's circuit breaker.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. []    Protocol Config
+    /// 1. [w+s] Protocol Admin
+    /// 2. [w]   Order Tracker
+    /// 3. []    Mint
+    /// 4. []    Mint Rules
+    /// 5. []    System Program
+    SetCircuitBreaker(SetCircuitBreakerInstruction),
 }
 
 impl ProtocolInstruction {
     pub fn pack(&self) -> Vec<u8> {
         let mut buf = vec![];
         match self {
-            Self::InitializeProtocol => {
+            Self::InitializeProtocol(data) => {
                 buf.push(0);
+                buf.append(&mut data.try_to_vec().unwrap());
             }
             Self::CreateMint(data) => {
                 buf.push(1);
                 buf.append(&mut data.try_to_vec().unwrap());
             }
-            Self::InitializeProfile => {
+            Self::InitializeProfile(data) => {
                 buf.push(2);
+                buf.append(&mut data.try_to_vec().unwrap());
+            }
+            Self::FreezeProfile => {
+                buf.push(3);
+            }
+            Self::ThawProfile => {
+                buf.push(4);
+            }
+            Self::AddToDenylist(data) => {
+                buf.push(5);
+                buf.append(&mut data.try_to_vec().unwrap());
+            }
+            Self::RemoveFromDenylist(data) => {
+                buf.push(6);
+                buf.append(&mut data.try_to_vec().unwrap());
+            }
+            Self::AddExemption(data) => {
+                buf.push(7);
+                buf.append(&mut data.try_to_vec().unwrap());
+            }
+            Self::RemoveExemption(data) => {
+                buf.push(8);
+                buf.append(&mut data.try_to_vec().unwrap());
+            }
+            Self::LinkWallet(data) => {
+                buf.push(9);
+                buf.append(&mut data.try_to_vec().unwrap());
+            }
+            Self::RecoverProfile => {
+                buf.push(10);
+            }
+            Self::UpdateMintRules(data) => {
+                buf.push(11);
+                buf.append(&mut data.try_to_vec().unwrap());
+            }
+            Self::SetOutflowLimit(data) => {
+                buf.push(12);
+                buf.append(&mut data.try_to_vec().unwrap());
+            }
+            Self::SetLockup(data) => {
+                buf.push(13);
+                buf.append(&mut data.try_to_vec().unwrap());
+            }
+            Self::SetTradingWindows(data) => {
+                buf.push(14);
+                buf.append(&mut data.try_to_vec().unwrap());
+            }
+            Self::SetTradingOverride(data) => {
+                buf.push(15);
+                buf.append(&mut data.try_to_vec().unwrap());
+            }
+            Self::InitializePairVolume => {
+                buf.push(16);
+            }
+            Self::ClearWashTradingFlag => {
+                buf.push(17);
+            }
+            Self::IssueTransferPermit(data) => {
+                buf.push(18);
+                buf.append(&mut data.try_to_vec().unwrap());
+            }
+            Self::ResetCircuitBreaker => {
+                buf.push(19);
+            }
+            Self::SetProgramFilter(data) => {
+                buf.push(20);
+                buf.append(&mut data.try_to_vec().unwrap());
+            }
+            Self::InitializeMintActivity => {
+                buf.push(21);
+            }
+            Self::AddBalanceCapExemption(data) => {
+                buf.push(22);
+                buf.append(&mut data.try_to_vec().unwrap());
+            }
+            Self::RemoveBalanceCapExemption(data) => {
+                buf.push(23);
+                buf.append(&mut data.try_to_vec().unwrap());
+            }
+            Self::InitializeWalletLink(data) => {
+                buf.push(24);
+                buf.append(&mut data.try_to_vec().unwrap());
+            }
+            Self::SetCircuitBreaker(data) => {
+                buf.push(25);
+                buf.append(&mut data.try_to_vec().unwrap());
             }
         }
         buf
@@ -76,78 +473,1277 @@ impl ProtocolInstruction {
         }
         let (discriminator, rest) = input.split_first().unwrap();
         Ok(match discriminator {
-            0 => Self::InitializeProtocol,
+            0 => {
+                let data = NamespaceInstruction::try_from_slice(rest)?;
+                Self::InitializeProtocol(data)
+            }
             1 => {
                 let data = CreateMintInstruction::try_from_slice(rest)?;
                 Self::CreateMint(data)
             }
-            2 => Self::InitializeProfile,
+            2 => {
+                let data = NamespaceInstruction::try_from_slice(rest)?;
+                Self::InitializeProfile(data)
+            }
+            3 => Self::FreezeProfile,
+            4 => Self::ThawProfile,
+            5 => {
+                let data = UpdateDenylistInstruction::try_from_slice(rest)?;
+                Self::AddToDenylist(data)
+            }
+            6 => {
+                let data = UpdateDenylistInstruction::try_from_slice(rest)?;
+                Self::RemoveFromDenylist(data)
+            }
+            7 => {
+                let data = UpdateExemptionsInstruction::try_from_slice(rest)?;
+                Self::AddExemption(data)
+            }
+            8 => {
+                let data = UpdateExemptionsInstruction::try_from_slice(rest)?;
+                Self::RemoveExemption(data)
+            }
+            9 => {
+                let data = NamespaceInstruction::try_from_slice(rest)?;
+                Self::LinkWallet(data)
+            }
+            10 => Self::RecoverProfile,
+            11 => {
+                let data = MintRules::try_from_slice(rest)?;
+                Self::UpdateMintRules(data)
+            }
+            12 => {
+                let data = SetOutflowLimitInstruction::try_from_slice(rest)?;
+                Self::SetOutflowLimit(data)
+            }
+            13 => {
+                let data = Lockup::try_from_slice(rest)?;
+                Self::SetLockup(data)
+            }
+            14 => {
+                let data = SetTradingWindowsInstruction::try_from_slice(rest)?;
+                Self::SetTradingWindows(data)
+            }
+            15 => {
+                let data = SetTradingOverrideInstruction::try_from_slice(rest)?;
+                Self::SetTradingOverride(data)
+            }
+            16 => Self::InitializePairVolume,
+            17 => Self::ClearWashTradingFlag,
+            18 => {
+                let data = IssueTransferPermitInstruction::try_from_slice(rest)?;
+                Self::IssueTransferPermit(data)
+            }
+            19 => Self::ResetCircuitBreaker,
+            20 => {
+                let data = SetProgramFilterInstruction::try_from_slice(rest)?;
+                Self::SetProgramFilter(data)
+            }
+            21 => Self::InitializeMintActivity,
+            22 => {
+                let data = UpdateExemptionsInstruction::try_from_slice(rest)?;
+                Self::AddBalanceCapExemption(data)
+            }
+            23 => {
+                let data = UpdateExemptionsInstruction::try_from_slice(rest)?;
+                Self::RemoveBalanceCapExemption(data)
+            }
+            24 => {
+                let data = NamespaceInstruction::try_from_slice(rest)?;
+                Self::InitializeWalletLink(data)
+            }
+            25 => {
+                let data = SetCircuitBreakerInstruction::try_from_slice(rest)?;
+                Self::SetCircuitBreaker(data)
+            }
             _ => return Err(ProgramError::InvalidInstructionData),
         })
     }
 }
 
-pub fn initialize_protocol(payer_address: &Pubkey) -> Instruction {
+pub fn initialize_protocol(namespace: &Pubkey, payer_address: &Pubkey) -> Instruction {
+    initialize_protocol_with_program_id(namespace, payer_address, &crate::id())
+}
+
+pub fn initialize_protocol_with_program_id(
+    namespace: &Pubkey,
+    payer_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Instruction {
+    let namespace = *namespace;
     Instruction {
-        program_id: crate::id(),
+        program_id: *program_id,
         accounts: vec![
-            AccountMeta::new(crate::state::Soulbound::address(), false),
-            AccountMeta::new(crate::state::OrderTracker::address(), false),
+            AccountMeta::new(
+                crate::state::Soulbound::address_with_program_id(&namespace, program_id),
+                false,
+            ),
+            AccountMeta::new(
+                crate::state::OrderTracker::address_with_program_id(&namespace, program_id),
+                false,
+            ),
+            AccountMeta::new(
+                crate::state::ProtocolConfig::address_with_program_id(&namespace, program_id),
+                false,
+            ),
+            AccountMeta::new(
+                crate::state::Denylist::address_with_program_id(&namespace, program_id),
+                false,
+            ),
             AccountMeta::new(*payer_address, true),
             AccountMeta::new_readonly(spl_token_2022::id(), false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            if namespace == crate::state::DEFAULT_NAMESPACE {
+                AccountMeta::new_readonly(crate::state::get_program_data_address(program_id), false)
+            } else {
+                AccountMeta::new_readonly(namespace, true)
+            },
+            AccountMeta::new(
+                crate::state::MintAuthority::address_with_program_id(&namespace, program_id),
+                false,
+            ),
         ],
-        data: ProtocolInstruction::InitializeProtocol.pack(),
+        data: ProtocolInstruction::InitializeProtocol(NamespaceInstruction { namespace }).pack(),
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_mint(
     mint_address: &Pubkey,
     mint_authority: &Pubkey,
+    admin_address: &Pubkey,
+    decimals: u8,
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    rules: &MintRules,
+) -> Instruction {
+    create_mint_with_program_id(
+        mint_address,
+        mint_authority,
+        admin_address,
+        decimals,
+        name,
+        symbol,
+        uri,
+        rules,
+        &crate::id(),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_mint_with_program_id(
+    mint_address: &Pubkey,
+    mint_authority: &Pubkey,
+    admin_address: &Pubkey,
     decimals: u8,
     name: &str,
     symbol: &str,
     uri: &str,
+    rules: &MintRules,
+    program_id: &Pubkey,
 ) -> Instruction {
     let mint_authority = *mint_authority;
     let name = name.to_string();
     let symbol = symbol.to_string();
     let uri = uri.to_string();
+    let rules = rules.clone();
     Instruction {
-        program_id: crate::id(),
+        program_id: *program_id,
         accounts: vec![
             AccountMeta::new(*mint_address, false),
             AccountMeta::new(
-                get_extra_account_metas_address(mint_address, &crate::id()),
+                get_extra_account_metas_address(mint_address, program_id),
                 false,
             ),
             AccountMeta::new(mint_authority, true),
             AccountMeta::new_readonly(spl_token_2022::id(), false),
+            AccountMeta::new(
+                crate::state::Exemptions::address_with_program_id(mint_address, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new(
+                crate::state::MintRules::address_with_program_id(mint_address, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(rules.credential_mint, false),
+            AccountMeta::new(
+                crate::state::TransferHistory::address_with_program_id(mint_address, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                crate::state::ProtocolConfig::address_with_program_id(&rules.namespace, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(*admin_address, true),
+            AccountMeta::new(
+                crate::state::OrderTracker::address_with_program_id(&rules.namespace, program_id),
+                false,
+            ),
         ],
         data: ProtocolInstruction::CreateMint(CreateMintInstruction {
             decimals,
             name,
             symbol,
             uri,
+            rules,
         })
         .pack(),
     }
 }
 
-pub fn initialize_profile(wallet_address: &Pubkey) -> Instruction {
+pub fn initialize_profile(namespace: &Pubkey, wallet_address: &Pubkey) -> Instruction {
+    initialize_profile_with_program_id(namespace, wallet_address, &crate::id())
+}
+
+pub fn initialize_profile_with_program_id(
+    namespace: &Pubkey,
+    wallet_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Instruction {
+    let namespace = *namespace;
     Instruction {
-        program_id: crate::id(),
+        program_id: *program_id,
         accounts: vec![
-            AccountMeta::new(crate::state::Soulbound::address(), false),
             AccountMeta::new(
-                crate::state::Soulbound::token_account(wallet_address),
+                crate::state::Soulbound::address_with_program_id(&namespace, program_id),
+                false,
+            ),
+            AccountMeta::new(
+                crate::state::Soulbound::token_account_with_program_id(
+                    &namespace,
+                    wallet_address,
+                    program_id,
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                crate::state::Profile::address_with_program_id(
+                    &namespace,
+                    wallet_address,
+                    program_id,
+                ),
                 false,
             ),
-            AccountMeta::new(crate::state::Profile::address(wallet_address), false),
             AccountMeta::new(*wallet_address, true),
-            AccountMeta::new_readonly(crate::state::MintAuthority::address(), false),
+            AccountMeta::new_readonly(
+                crate::state::MintAuthority::address_with_program_id(&namespace, program_id),
+                false,
+            ),
             AccountMeta::new_readonly(spl_token_2022::id(), false),
             AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new(
+                crate::state::WalletLink::address_with_program_id(
+                    &namespace,
+                    wallet_address,
+                    program_id,
+                ),
+                false,
+            ),
+        ],
+        data: ProtocolInstruction::InitializeProfile(NamespaceInstruction { namespace }).pack(),
+    }
+}
+
+pub fn freeze_profile(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    wallet_address: &Pubkey,
+) -> Instruction {
+    freeze_profile_with_program_id(namespace, admin_address, wallet_address, &crate::id())
+}
+
+pub fn freeze_profile_with_program_id(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    wallet_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(
+                crate::state::ProtocolConfig::address_with_program_id(namespace, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(*admin_address, true),
+            AccountMeta::new(
+                crate::state::Profile::address_with_program_id(
+                    namespace,
+                    wallet_address,
+                    program_id,
+                ),
+                false,
+            ),
+        ],
+        data: ProtocolInstruction::FreezeProfile.pack(),
+    }
+}
+
+pub fn thaw_profile(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    wallet_address: &Pubkey,
+) -> Instruction {
+    thaw_profile_with_program_id(namespace, admin_address, wallet_address, &crate::id())
+}
+
+pub fn thaw_profile_with_program_id(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    wallet_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(
+                crate::state::ProtocolConfig::address_with_program_id(namespace, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(*admin_address, true),
+            AccountMeta::new(
+                crate::state::Profile::address_with_program_id(
+                    namespace,
+                    wallet_address,
+                    program_id,
+                ),
+                false,
+            ),
+        ],
+        data: ProtocolInstruction::ThawProfile.pack(),
+    }
+}
+
+pub fn add_to_denylist(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    wallet_address: &Pubkey,
+) -> Instruction {
+    add_to_denylist_with_program_id(namespace, admin_address, wallet_address, &crate::id())
+}
+
+pub fn add_to_denylist_with_program_id(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    wallet_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Instruction {
+    let wallet_address = *wallet_address;
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(
+                crate::state::ProtocolConfig::address_with_program_id(namespace, program_id),
+                false,
+            ),
+            AccountMeta::new(*admin_address, true),
+            AccountMeta::new(
+                crate::state::Denylist::address_with_program_id(namespace, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: ProtocolInstruction::AddToDenylist(UpdateDenylistInstruction { wallet_address })
+            .pack(),
+    }
+}
+
+pub fn remove_from_denylist(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    wallet_address: &Pubkey,
+) -> Instruction {
+    remove_from_denylist_with_program_id(namespace, admin_address, wallet_address, &crate::id())
+}
+
+pub fn remove_from_denylist_with_program_id(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    wallet_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Instruction {
+    let wallet_address = *wallet_address;
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(
+                crate::state::ProtocolConfig::address_with_program_id(namespace, program_id),
+                false,
+            ),
+            AccountMeta::new(*admin_address, true),
+            AccountMeta::new(
+                crate::state::Denylist::address_with_program_id(namespace, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: ProtocolInstruction::RemoveFromDenylist(UpdateDenylistInstruction { wallet_address })
+            .pack(),
+    }
+}
+
+pub fn add_exemption(
+    mint_address: &Pubkey,
+    mint_authority: &Pubkey,
+    owner_address: &Pubkey,
+) -> Instruction {
+    add_exemption_with_program_id(mint_address, mint_authority, owner_address, &crate::id())
+}
+
+pub fn add_exemption_with_program_id(
+    mint_address: &Pubkey,
+    mint_authority: &Pubkey,
+    owner_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Instruction {
+    let owner_address = *owner_address;
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*mint_address, false),
+            AccountMeta::new(*mint_authority, true),
+            AccountMeta::new(
+                crate::state::Exemptions::address_with_program_id(mint_address, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: ProtocolInstruction::AddExemption(UpdateExemptionsInstruction { owner_address })
+            .pack(),
+    }
+}
+
+pub fn remove_exemption(
+    mint_address: &Pubkey,
+    mint_authority: &Pubkey,
+    owner_address: &Pubkey,
+) -> Instruction {
+    remove_exemption_with_program_id(mint_address, mint_authority, owner_address, &crate::id())
+}
+
+pub fn remove_exemption_with_program_id(
+    mint_address: &Pubkey,
+    mint_authority: &Pubkey,
+    owner_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Instruction {
+    let owner_address = *owner_address;
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*mint_address, false),
+            AccountMeta::new(*mint_authority, true),
+            AccountMeta::new(
+                crate::state::Exemptions::address_with_program_id(mint_address, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: ProtocolInstruction::RemoveExemption(UpdateExemptionsInstruction { owner_address })
+            .pack(),
+    }
+}
+
+pub fn link_wallet(
+    namespace: &Pubkey,
+    primary_wallet_address: &Pubkey,
+    secondary_wallet_address: &Pubkey,
+) -> Instruction {
+    link_wallet_with_program_id(
+        namespace,
+        primary_wallet_address,
+        secondary_wallet_address,
+        &crate::id(),
+    )
+}
+
+pub fn link_wallet_with_program_id(
+    namespace: &Pubkey,
+    primary_wallet_address: &Pubkey,
+    secondary_wallet_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Instruction {
+    let namespace = *namespace;
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(
+                crate::state::Profile::address_with_program_id(
+                    &namespace,
+                    primary_wallet_address,
+                    program_id,
+                ),
+                false,
+            ),
+            AccountMeta::new(*primary_wallet_address, true),
+            AccountMeta::new_readonly(
+                crate::state::Profile::address_with_program_id(
+                    &namespace,
+                    secondary_wallet_address,
+                    program_id,
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(*secondary_wallet_address, true),
+            AccountMeta::new(
+                crate::state::WalletLink::address_with_program_id(
+                    &namespace,
+                    secondary_wallet_address,
+                    program_id,
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(
+                crate::state::WalletLink::address_with_program_id(
+                    &namespace,
+                    primary_wallet_address,
+                    program_id,
+                ),
+                false,
+            ),
+        ],
+        data: ProtocolInstruction::LinkWallet(NamespaceInstruction { namespace }).pack(),
+    }
+}
+
+pub fn recover_profile(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    old_wallet_address: &Pubkey,
+    new_wallet_address: &Pubkey,
+    linked_wallet_addresses: &[Pubkey],
+) -> Instruction {
+    recover_profile_with_program_id(
+        namespace,
+        admin_address,
+        old_wallet_address,
+        new_wallet_address,
+        linked_wallet_addresses,
+        &crate::id(),
+    )
+}
+
+pub fn recover_profile_with_program_id(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    old_wallet_address: &Pubkey,
+    new_wallet_address: &Pubkey,
+    linked_wallet_addresses: &[Pubkey],
+    program_id: &Pubkey,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(
+            crate::state::ProtocolConfig::address_with_program_id(namespace, program_id),
+            false,
+        ),
+        AccountMeta::new(*admin_address, true),
+        AccountMeta::new(
+            crate::state::Soulbound::address_with_program_id(namespace, program_id),
+            false,
+        ),
+        AccountMeta::new_readonly(
+            crate::state::MintAuthority::address_with_program_id(namespace, program_id),
+            false,
+        ),
+        AccountMeta::new(
+            crate::state::Soulbound::token_account_with_program_id(
+                namespace,
+                old_wallet_address,
+                program_id,
+            ),
+            false,
+        ),
+        AccountMeta::new(
+            crate::state::Profile::address_with_program_id(
+                namespace,
+                old_wallet_address,
+                program_id,
+            ),
+            false,
+        ),
+        AccountMeta::new(
+            crate::state::WalletLink::address_with_program_id(
+                namespace,
+                old_wallet_address,
+                program_id,
+            ),
+            false,
+        ),
+        AccountMeta::new_readonly(*old_wallet_address, false),
+        AccountMeta::new(
+            crate::state::Soulbound::token_account_with_program_id(
+                namespace,
+                new_wallet_address,
+                program_id,
+            ),
+            false,
+        ),
+        AccountMeta::new(
+            crate::state::Profile::address_with_program_id(
+                namespace,
+                new_wallet_address,
+                program_id,
+            ),
+            false,
+        ),
+        AccountMeta::new(
+            crate::state::WalletLink::address_with_program_id(
+                namespace,
+                new_wallet_address,
+                program_id,
+            ),
+            false,
+        ),
+        AccountMeta::new_readonly(*new_wallet_address, true),
+        AccountMeta::new_readonly(spl_token_2022::id(), false),
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+    ];
+    for linked_wallet_address in linked_wallet_addresses {
+        accounts.push(AccountMeta::new_readonly(*linked_wallet_address, false));
+        accounts.push(AccountMeta::new(
+            crate::state::WalletLink::address_with_program_id(
+                namespace,
+                linked_wallet_address,
+                program_id,
+            ),
+            false,
+        ));
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ProtocolInstruction::RecoverProfile.pack(),
+    }
+}
+
+pub fn update_mint_rules(
+    mint_address: &Pubkey,
+    mint_authority: &Pubkey,
+    rules: &MintRules,
+) -> Instruction {
+    update_mint_rules_with_program_id(mint_address, mint_authority, rules, &crate::id())
+}
+
+pub fn update_mint_rules_with_program_id(
+    mint_address: &Pubkey,
+    mint_authority: &Pubkey,
+    rules: &MintRules,
+    program_id: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*mint_address, false),
+            AccountMeta::new_readonly(*mint_authority, true),
+            AccountMeta::new(
+                crate::state::MintRules::address_with_program_id(mint_address, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(rules.credential_mint, false),
+        ],
+        data: ProtocolInstruction::UpdateMintRules(rules.clone()).pack(),
+    }
+}
+
+pub fn set_outflow_limit(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    mint_address: &Pubkey,
+    wallet_address: &Pubkey,
+    outflow_limit: u64,
+) -> Instruction {
+    set_outflow_limit_with_program_id(
+        namespace,
+        admin_address,
+        mint_address,
+        wallet_address,
+        outflow_limit,
+        &crate::id(),
+    )
+}
+
+pub fn set_outflow_limit_with_program_id(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    mint_address: &Pubkey,
+    wallet_address: &Pubkey,
+    outflow_limit: u64,
+    program_id: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(
+                crate::state::ProtocolConfig::address_with_program_id(namespace, program_id),
+                false,
+            ),
+            AccountMeta::new(*admin_address, true),
+            AccountMeta::new(
+                crate::state::MintActivity::address_with_program_id(
+                    wallet_address,
+                    mint_address,
+                    program_id,
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(*wallet_address, false),
+            AccountMeta::new_readonly(*mint_address, false),
+            AccountMeta::new_readonly(
+                MintRules::address_with_program_id(mint_address, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(
+                crate::state::Profile::address_with_program_id(
+                    namespace,
+                    wallet_address,
+                    program_id,
+                ),
+                false,
+            ),
+        ],
+        data: ProtocolInstruction::SetOutflowLimit(SetOutflowLimitInstruction { outflow_limit })
+            .pack(),
+    }
+}
+
+pub fn set_lockup(
+    mint_address: &Pubkey,
+    mint_authority: &Pubkey,
+    wallet_address: &Pubkey,
+    lockup: &Lockup,
+) -> Instruction {
+    set_lockup_with_program_id(
+        mint_address,
+        mint_authority,
+        wallet_address,
+        lockup,
+        &crate::id(),
+    )
+}
+
+pub fn set_lockup_with_program_id(
+    mint_address: &Pubkey,
+    mint_authority: &Pubkey,
+    wallet_address: &Pubkey,
+    lockup: &Lockup,
+    program_id: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*mint_address, false),
+            AccountMeta::new(*mint_authority, true),
+            AccountMeta::new(
+                Lockup::address_with_program_id(wallet_address, mint_address, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(*wallet_address, false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: ProtocolInstruction::SetLockup(lockup.clone()).pack(),
+    }
+}
+
+pub fn set_trading_windows(
+    mint_address: &Pubkey,
+    mint_authority: &Pubkey,
+    windows: &[TradingWindow],
+) -> Instruction {
+    set_trading_windows_with_program_id(mint_address, mint_authority, windows, &crate::id())
+}
+
+pub fn set_trading_windows_with_program_id(
+    mint_address: &Pubkey,
+    mint_authority: &Pubkey,
+    windows: &[TradingWindow],
+    program_id: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*mint_address, false),
+            AccountMeta::new(*mint_authority, true),
+            AccountMeta::new(
+                crate::state::TradingSchedule::address_with_program_id(mint_address, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: ProtocolInstruction::SetTradingWindows(SetTradingWindowsInstruction {
+            windows: windows.to_vec(),
+        })
+        .pack(),
+    }
+}
+
+pub fn set_trading_override(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    mint_address: &Pubkey,
+    trading_override: TradingOverride,
+) -> Instruction {
+    set_trading_override_with_program_id(
+        namespace,
+        admin_address,
+        mint_address,
+        trading_override,
+        &crate::id(),
+    )
+}
+
+pub fn set_trading_override_with_program_id(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    mint_address: &Pubkey,
+    trading_override: TradingOverride,
+    program_id: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(
+                crate::state::ProtocolConfig::address_with_program_id(namespace, program_id),
+                false,
+            ),
+            AccountMeta::new(*admin_address, true),
+            AccountMeta::new_readonly(*mint_address, false),
+            AccountMeta::new(
+                crate::state::TradingSchedule::address_with_program_id(mint_address, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(
+                MintRules::address_with_program_id(mint_address, program_id),
+                false,
+            ),
+        ],
+        data: ProtocolInstruction::SetTradingOverride(SetTradingOverrideInstruction {
+            trading_override,
+        })
+        .pack(),
+    }
+}
+
+pub fn initialize_pair_volume(
+    payer_address: &Pubkey,
+    mint_address: &Pubkey,
+    first_wallet: &Pubkey,
+    second_wallet: &Pubkey,
+) -> Instruction {
+    initialize_pair_volume_with_program_id(
+        payer_address,
+        mint_address,
+        first_wallet,
+        second_wallet,
+        &crate::id(),
+    )
+}
+
+pub fn initialize_pair_volume_with_program_id(
+    payer_address: &Pubkey,
+    mint_address: &Pubkey,
+    first_wallet: &Pubkey,
+    second_wallet: &Pubkey,
+    program_id: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*payer_address, true),
+            AccountMeta::new_readonly(*mint_address, false),
+            AccountMeta::new_readonly(*first_wallet, false),
+            AccountMeta::new_readonly(*second_wallet, false),
+            AccountMeta::new(
+                crate::state::PairVolume::address_with_program_id(
+                    first_wallet,
+                    second_wallet,
+                    mint_address,
+                    program_id,
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                crate::state::PairVolume::address_with_program_id(
+                    second_wallet,
+                    first_wallet,
+                    mint_address,
+                    program_id,
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: ProtocolInstruction::InitializePairVolume.pack(),
+    }
+}
+
+pub fn clear_wash_trading_flag(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    wallet_address: &Pubkey,
+) -> Instruction {
+    clear_wash_trading_flag_with_program_id(namespace, admin_address, wallet_address, &crate::id())
+}
+
+pub fn clear_wash_trading_flag_with_program_id(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    wallet_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(
+                crate::state::ProtocolConfig::address_with_program_id(namespace, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(*admin_address, true),
+            AccountMeta::new(
+                crate::state::Profile::address_with_program_id(
+                    namespace,
+                    wallet_address,
+                    program_id,
+                ),
+                false,
+            ),
+        ],
+        data: ProtocolInstruction::ClearWashTradingFlag.pack(),
+    }
+}
+
+pub fn issue_transfer_permit(
+    mint_address: &Pubkey,
+    approver_address: &Pubkey,
+    source: &Pubkey,
+    destination: &Pubkey,
+    max_amount: u64,
+    expiry: i64,
+) -> Instruction {
+    issue_transfer_permit_with_program_id(
+        mint_address,
+        approver_address,
+        source,
+        destination,
+        max_amount,
+        expiry,
+        &crate::id(),
+    )
+}
+
+pub fn issue_transfer_permit_with_program_id(
+    mint_address: &Pubkey,
+    approver_address: &Pubkey,
+    source: &Pubkey,
+    destination: &Pubkey,
+    max_amount: u64,
+    expiry: i64,
+    program_id: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*mint_address, false),
+            AccountMeta::new_readonly(
+                MintRules::address_with_program_id(mint_address, program_id),
+                false,
+            ),
+            AccountMeta::new(*approver_address, true),
+            AccountMeta::new_readonly(*source, false),
+            AccountMeta::new_readonly(*destination, false),
+            AccountMeta::new(
+                crate::state::TransferPermit::address_with_program_id(
+                    source,
+                    destination,
+                    mint_address,
+                    program_id,
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: ProtocolInstruction::IssueTransferPermit(IssueTransferPermitInstruction {
+            max_amount,
+            expiry,
+        })
+        .pack(),
+    }
+}
+
+pub fn reset_circuit_breaker(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    mint_address: &Pubkey,
+) -> Instruction {
+    reset_circuit_breaker_with_program_id(namespace, admin_address, mint_address, &crate::id())
+}
+
+pub fn reset_circuit_breaker_with_program_id(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    mint_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(
+                crate::state::ProtocolConfig::address_with_program_id(namespace, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(*admin_address, true),
+            AccountMeta::new(
+                crate::state::OrderTracker::address_with_program_id(namespace, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(*mint_address, false),
+        ],
+        data: ProtocolInstruction::ResetCircuitBreaker.pack(),
+    }
+}
+
+pub fn set_circuit_breaker(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    mint_address: &Pubkey,
+    threshold: u64,
+    window_slots: u64,
+) -> Instruction {
+    set_circuit_breaker_with_program_id(
+        namespace,
+        admin_address,
+        mint_address,
+        threshold,
+        window_slots,
+        &crate::id(),
+    )
+}
+
+pub fn set_circuit_breaker_with_program_id(
+    namespace: &Pubkey,
+    admin_address: &Pubkey,
+    mint_address: &Pubkey,
+    threshold: u64,
+    window_slots: u64,
+    program_id: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(
+                crate::state::ProtocolConfig::address_with_program_id(namespace, program_id),
+                false,
+            ),
+            AccountMeta::new(*admin_address, true),
+            AccountMeta::new(
+                crate::state::OrderTracker::address_with_program_id(namespace, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(*mint_address, false),
+            AccountMeta::new_readonly(
+                MintRules::address_with_program_id(mint_address, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: ProtocolInstruction::SetCircuitBreaker(SetCircuitBreakerInstruction {
+            threshold,
+            window_slots,
+        })
+        .pack(),
+    }
+}
+
+pub fn set_program_filter(
+    mint_address: &Pubkey,
+    mint_authority: &Pubkey,
+    mode: ProgramFilterMode,
+    program_ids: &[Pubkey],
+) -> Instruction {
+    set_program_filter_with_program_id(
+        mint_address,
+        mint_authority,
+        mode,
+        program_ids,
+        &crate::id(),
+    )
+}
+
+pub fn set_program_filter_with_program_id(
+    mint_address: &Pubkey,
+    mint_authority: &Pubkey,
+    mode: ProgramFilterMode,
+    program_ids: &[Pubkey],
+    program_id: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*mint_address, false),
+            AccountMeta::new(*mint_authority, true),
+            AccountMeta::new(
+                crate::state::ProgramFilter::address_with_program_id(mint_address, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: ProtocolInstruction::SetProgramFilter(SetProgramFilterInstruction {
+            mode,
+            program_ids: program_ids.to_vec(),
+        })
+        .pack(),
+    }
+}
+
+pub fn initialize_mint_activity(
+    payer_address: &Pubkey,
+    mint_address: &Pubkey,
+    wallet_address: &Pubkey,
+) -> Instruction {
+    initialize_mint_activity_with_program_id(
+        payer_address,
+        mint_address,
+        wallet_address,
+        &crate::id(),
+    )
+}
+
+pub fn initialize_mint_activity_with_program_id(
+    payer_address: &Pubkey,
+    mint_address: &Pubkey,
+    wallet_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*payer_address, true),
+            AccountMeta::new_readonly(*mint_address, false),
+            AccountMeta::new_readonly(*wallet_address, false),
+            AccountMeta::new(
+                crate::state::MintActivity::address_with_program_id(
+                    wallet_address,
+                    mint_address,
+                    program_id,
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: ProtocolInstruction::InitializeMintActivity.pack(),
+    }
+}
+
+pub fn add_balance_cap_exemption(
+    mint_address: &Pubkey,
+    mint_authority: &Pubkey,
+    owner_address: &Pubkey,
+) -> Instruction {
+    add_balance_cap_exemption_with_program_id(
+        mint_address,
+        mint_authority,
+        owner_address,
+        &crate::id(),
+    )
+}
+
+pub fn add_balance_cap_exemption_with_program_id(
+    mint_address: &Pubkey,
+    mint_authority: &Pubkey,
+    owner_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Instruction {
+    let owner_address = *owner_address;
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*mint_address, false),
+            AccountMeta::new(*mint_authority, true),
+            AccountMeta::new(
+                crate::state::Exemptions::address_with_program_id(mint_address, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: ProtocolInstruction::AddBalanceCapExemption(UpdateExemptionsInstruction {
+            owner_address,
+        })
+        .pack(),
+    }
+}
+
+pub fn remove_balance_cap_exemption(
+    mint_address: &Pubkey,
+    mint_authority: &Pubkey,
+    owner_address: &Pubkey,
+) -> Instruction {
+    remove_balance_cap_exemption_with_program_id(
+        mint_address,
+        mint_authority,
+        owner_address,
+        &crate::id(),
+    )
+}
+
+pub fn remove_balance_cap_exemption_with_program_id(
+    mint_address: &Pubkey,
+    mint_authority: &Pubkey,
+    owner_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Instruction {
+    let owner_address = *owner_address;
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*mint_address, false),
+            AccountMeta::new(*mint_authority, true),
+            AccountMeta::new(
+                crate::state::Exemptions::address_with_program_id(mint_address, program_id),
+                false,
+            ),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+        data: ProtocolInstruction::RemoveBalanceCapExemption(UpdateExemptionsInstruction {
+            owner_address,
+        })
+        .pack(),
+    }
+}
+
+pub fn initialize_wallet_link(
+    namespace: &Pubkey,
+    payer_address: &Pubkey,
+    wallet_address: &Pubkey,
+) -> Instruction {
+    initialize_wallet_link_with_program_id(namespace, payer_address, wallet_address, &crate::id())
+}
+
+pub fn initialize_wallet_link_with_program_id(
+    namespace: &Pubkey,
+    payer_address: &Pubkey,
+    wallet_address: &Pubkey,
+    program_id: &Pubkey,
+) -> Instruction {
+    let namespace = *namespace;
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*payer_address, true),
+            AccountMeta::new_readonly(*wallet_address, false),
+            AccountMeta::new(
+                crate::state::WalletLink::address_with_program_id(
+                    &namespace,
+                    wallet_address,
+                    program_id,
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
         ],
-        data: ProtocolInstruction::InitializeProfile.pack(),
+        data: ProtocolInstruction::InitializeWalletLink(NamespaceInstruction { namespace }).pack(),
     }
 }
@@ -1,7 +1,10 @@
 #[cfg(not(feature = "no-entrypoint"))]
 mod entrypoint;
 pub mod error;
+pub mod events;
 pub mod instruction;
+#[cfg(not(target_os = "solana"))]
+pub mod offchain;
 pub mod processor;
 pub mod state;
 
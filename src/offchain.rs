@@ -0,0 +1,86 @@
+//! Helpers for offchain clients, such as indexers, to decode the protocol's
+//! events and errors from transaction results.
+//!
+//! Events are only emitted to the program logs, so they are decoded from a
+//! transaction's log messages rather than its inner instructions.
+//!
+//! This module is not part of the on-chain build.
+
+use {
+    crate::{
+        error::ProtocolError,
+        events::{ProtocolEvent, VersionedEvent},
+    },
+    base64::{engine::general_purpose::STANDARD, Engine},
+    borsh::BorshDeserialize,
+    num_traits::FromPrimitive,
+    solana_program::{instruction::InstructionError, pubkey::Pubkey},
+    std::io,
+};
+
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+/// Decodes the events the protocol emitted in a transaction's log messages.
+pub fn parse_events(logs: &[String]) -> Vec<Result<ProtocolEvent, io::Error>> {
+    parse_events_with_program_id(logs, &crate::id())
+}
+
+/// Decodes the events the protocol, deployed under the given program ID,
+/// emitted in a transaction's log messages.
+///
+/// Data logged by other programs, including programs invoked by the protocol,
+/// is ignored. Each entry of the protocol's data is decoded on its own, so data
+/// that can't be decoded, such as an event from a newer version of the
+/// protocol, is reported as an error in its place without affecting the other
+/// events.
+pub fn parse_events_with_program_id(
+    logs: &[String],
+    program_id: &Pubkey,
+) -> Vec<Result<ProtocolEvent, io::Error>> {
+    let program_id = program_id.to_string();
+    let mut invoked = Vec::new();
+    let mut events = Vec::new();
+    for log in logs {
+        if let Some(data) = log.strip_prefix(PROGRAM_DATA_PREFIX) {
+            if invoked.last() == Some(&program_id.as_str()) {
+                events.push(decode_event(data));
+            }
+        } else if let Some(rest) = log.strip_prefix("Program ") {
+            let mut words = rest.split(' ');
+            match (words.next(), words.next()) {
+                (Some(id), Some("invoke")) => invoked.push(id),
+                (Some(_), Some("success")) | (Some(_), Some("failed:")) => {
+                    invoked.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+    events
+}
+
+/// Decodes an event from the base64 data of a `Program data:` log message.
+pub fn decode_event(data: &str) -> Result<ProtocolEvent, io::Error> {
+    let data = STANDARD
+        .decode(data)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    match VersionedEvent::try_from_slice(&data)? {
+        VersionedEvent::V1(event) => Ok(event),
+    }
+}
+
+/// Maps a custom error code to the protocol error it represents.
+pub fn decode_error_code(code: u32) -> Option<ProtocolError> {
+    ProtocolError::from_u32(code)
+}
+
+/// Maps an instruction error to the protocol error it represents, if any.
+///
+/// Custom error codes are not namespaced by program, so the error must have
+/// been returned by one of the protocol's instructions.
+pub fn decode_instruction_error(error: &InstructionError) -> Option<ProtocolError> {
+    match error {
+        InstructionError::Custom(code) => decode_error_code(*code),
+        _ => None,
+    }
+}
@@ -0,0 +1,84 @@
+use {
+    crate::{error::ProtocolError, events::ProtocolEvent, state::MintActivity},
+    borsh::BorshSerialize,
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        program::invoke_signed,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+/// Creates a primary wallet's mint activity account, if it does not exist
+/// yet.
+pub(crate) fn create_mint_activity<'a>(
+    program_id: &Pubkey,
+    activity_info: &AccountInfo<'a>,
+    payer_info: &AccountInfo<'a>,
+    wallet_address: &Pubkey,
+    mint: &Pubkey,
+) -> ProgramResult {
+    let (address, bump) =
+        MintActivity::address_with_bump_and_program_id(wallet_address, mint, program_id);
+    if activity_info.key != &address {
+        return Err(ProtocolError::IncorrectMintActivity.into());
+    }
+    if activity_info.lamports() != 0 {
+        return Ok(());
+    }
+
+    let signer_seeds = &[
+        MintActivity::seed(),
+        wallet_address.as_ref(),
+        mint.as_ref(),
+        &[bump],
+    ];
+    invoke_signed(
+        &MintActivity::create_account_instruction(payer_info.key, wallet_address, mint, program_id),
+        &[activity_info.clone(), payer_info.clone()],
+        &[signer_seeds],
+    )?;
+    MintActivity::default().serialize(&mut &mut activity_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+pub fn process_initialize_mint_activity(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Accounts expected by this instruction:
+    // 0. [w+s] Payer
+    // 1. []    Mint
+    // 2. []    Primary Wallet
+    // 3. [w]   Mint Activity
+    // 4. []    System Program
+    let payer_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let wallet_info = next_account_info(accounts_iter)?;
+    let activity_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    create_mint_activity(
+        program_id,
+        activity_info,
+        payer_info,
+        wallet_info.key,
+        mint_info.key,
+    )?;
+
+    ProtocolEvent::MintActivityInitialized {
+        mint: *mint_info.key,
+        wallet: *wallet_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
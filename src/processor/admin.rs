@@ -0,0 +1,281 @@
+#[allow(deprecated)]
+use solana_program::borsh0_10::get_instance_packed_len;
+use {
+    crate::{
+        error::ProtocolError,
+        events::ProtocolEvent,
+        instruction::SetCircuitBreakerInstruction,
+        processor::{
+            activity::create_mint_activity,
+            utils::{get_mint_rules, resize_account},
+        },
+        state::{MintActivity, OrderTracker, Profile, ProtocolConfig},
+    },
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+/// Asserts the provided config account is a namespace's protocol config and
+/// the admin account is that namespace's admin, signing the transaction.
+/// Returns the config, whose namespace the instruction's accounts belong to.
+pub(crate) fn check_admin(
+    program_id: &Pubkey,
+    config_info: &AccountInfo,
+    admin_info: &AccountInfo,
+) -> Result<ProtocolConfig, ProgramError> {
+    if config_info.owner != program_id {
+        return Err(ProtocolError::IncorrectProtocolConfig.into());
+    }
+    let config = ProtocolConfig::try_from_slice(&config_info.data.borrow())
+        .map_err(|_| ProtocolError::IncorrectProtocolConfig)?;
+    if config_info.key != &ProtocolConfig::address_with_program_id(&config.namespace, program_id) {
+        return Err(ProtocolError::IncorrectProtocolConfig.into());
+    }
+    if admin_info.key != &config.admin {
+        return Err(ProtocolError::IncorrectAdmin.into());
+    }
+    if !admin_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(config)
+}
+
+fn update_profile(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    update: impl FnOnce(&mut Profile),
+) -> Result<(ProtocolConfig, Profile), ProgramError> {
+    let accounts_iter = &mut accounts.iter();
+
+    // Accounts expected by this instruction:
+    // 0. []    Protocol Config
+    // 1. [s]   Protocol Admin
+    // 2. [w]   User's Profile
+    let config_info = next_account_info(accounts_iter)?;
+    let admin_info = next_account_info(accounts_iter)?;
+    let profile_info = next_account_info(accounts_iter)?;
+
+    let config = check_admin(program_id, config_info, admin_info)?;
+
+    // Assert the user's profile exists in the admin's namespace.
+    if profile_info.lamports() == 0 || profile_info.owner != program_id {
+        return Err(ProtocolError::ProfileNotInitialized.into());
+    }
+
+    let mut profile = Profile::try_from_slice(&profile_info.data.borrow())?;
+    if profile_info.key
+        != &Profile::address_with_program_id(&config.namespace, &profile.wallet_address, program_id)
+    {
+        return Err(ProtocolError::IncorrectNamespace.into());
+    }
+    update(&mut profile);
+    profile.serialize(&mut &mut profile_info.data.borrow_mut()[..])?;
+
+    Ok((config, profile))
+}
+
+pub fn process_freeze_profile(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let (config, profile) = update_profile(program_id, accounts, |profile| profile.frozen = true)?;
+    ProtocolEvent::ProfileFrozen {
+        namespace: config.namespace,
+        wallet: profile.wallet_address,
+    }
+    .emit();
+    Ok(())
+}
+
+pub fn process_thaw_profile(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let (config, profile) = update_profile(program_id, accounts, |profile| profile.frozen = false)?;
+    ProtocolEvent::ProfileThawed {
+        namespace: config.namespace,
+        wallet: profile.wallet_address,
+    }
+    .emit();
+    Ok(())
+}
+
+pub fn process_set_outflow_limit(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    outflow_limit: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Accounts expected by this instruction:
+    // 0. []    Protocol Config
+    // 1. [w+s] Protocol Admin
+    // 2. [w]   Mint Activity
+    // 3. []    Primary Wallet
+    // 4. []    Mint
+    // 5. []    Mint Rules
+    // 6. []    System Program
+    // 7. []    Profile
+    let config_info = next_account_info(accounts_iter)?;
+    let admin_info = next_account_info(accounts_iter)?;
+    let activity_info = next_account_info(accounts_iter)?;
+    let wallet_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let mint_rules_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+    let profile_info = next_account_info(accounts_iter)?;
+
+    let config = check_admin(program_id, config_info, admin_info)?;
+
+    // Assert the mint belongs to the admin's namespace.
+    let rules = get_mint_rules(program_id, mint_info, mint_rules_info)?;
+    if rules.namespace != config.namespace {
+        return Err(ProtocolError::IncorrectNamespace.into());
+    }
+
+    // Assert the wallet is a profile's primary wallet, as the transfer hook
+    // tracks outflow across every wallet linked to the profile under it.
+    if profile_info.key
+        != &Profile::address_with_program_id(&config.namespace, wallet_info.key, program_id)
+        || profile_info.lamports() == 0
+        || profile_info.owner != program_id
+    {
+        return Err(ProtocolError::ProfileNotInitialized.into());
+    }
+
+    create_mint_activity(
+        program_id,
+        activity_info,
+        admin_info,
+        wallet_info.key,
+        mint_info.key,
+    )?;
+    let mut activity = MintActivity::try_from_slice(&activity_info.data.borrow())?;
+    activity.outflow_limit = outflow_limit;
+    activity.serialize(&mut &mut activity_info.data.borrow_mut()[..])?;
+
+    ProtocolEvent::OutflowLimitSet {
+        namespace: config.namespace,
+        mint: *mint_info.key,
+        wallet: *wallet_info.key,
+        outflow_limit,
+    }
+    .emit();
+    Ok(())
+}
+
+pub fn process_clear_wash_trading_flag(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let (config, profile) = update_profile(program_id, accounts, |profile| {
+        profile.wash_trading_flagged = false
+    })?;
+    ProtocolEvent::WashTradingFlagCleared {
+        namespace: config.namespace,
+        wallet: profile.wallet_address,
+    }
+    .emit();
+    Ok(())
+}
+
+pub fn process_set_circuit_breaker(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: SetCircuitBreakerInstruction,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Accounts expected by this instruction:
+    // 0. []    Protocol Config
+    // 1. [w+s] Protocol Admin
+    // 2. [w]   Order Tracker
+    // 3. []    Mint
+    // 4. []    Mint Rules
+    // 5. []    System Program
+    let config_info = next_account_info(accounts_iter)?;
+    let admin_info = next_account_info(accounts_iter)?;
+    let order_tracker_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let mint_rules_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    let config = check_admin(program_id, config_info, admin_info)?;
+
+    // Assert the mint belongs to the admin's namespace.
+    let rules = get_mint_rules(program_id, mint_info, mint_rules_info)?;
+    if rules.namespace != config.namespace {
+        return Err(ProtocolError::IncorrectNamespace.into());
+    }
+
+    if order_tracker_info.key
+        != &OrderTracker::address_with_program_id(&config.namespace, program_id)
+        || order_tracker_info.owner != program_id
+    {
+        return Err(ProtocolError::IncorrectOrderTracker.into());
+    }
+
+    let mut order_tracker = OrderTracker::try_from_slice(&order_tracker_info.data.borrow())?;
+    order_tracker.set_circuit_breaker(mint_info.key, data.threshold, data.window_slots);
+
+    resize_account(
+        order_tracker_info,
+        admin_info,
+        #[allow(deprecated)]
+        get_instance_packed_len(&order_tracker)?,
+    )?;
+    order_tracker.serialize(&mut &mut order_tracker_info.data.borrow_mut()[..])?;
+
+    ProtocolEvent::CircuitBreakerSet {
+        namespace: config.namespace,
+        mint: *mint_info.key,
+        threshold: data.threshold,
+        window_slots: data.window_slots,
+    }
+    .emit();
+
+    Ok(())
+}
+
+pub fn process_reset_circuit_breaker(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Accounts expected by this instruction:
+    // 0. []    Protocol Config
+    // 1. [s]   Protocol Admin
+    // 2. [w]   Order Tracker
+    // 3. []    Mint
+    let config_info = next_account_info(accounts_iter)?;
+    let admin_info = next_account_info(accounts_iter)?;
+    let order_tracker_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+
+    let config = check_admin(program_id, config_info, admin_info)?;
+
+    if order_tracker_info.key
+        != &OrderTracker::address_with_program_id(&config.namespace, program_id)
+        || order_tracker_info.owner != program_id
+    {
+        return Err(ProtocolError::IncorrectOrderTracker.into());
+    }
+
+    let mut order_tracker = OrderTracker::try_from_slice(&order_tracker_info.data.borrow())?;
+    order_tracker.reset_circuit_breaker(mint_info.key);
+
+    order_tracker_info.realloc(
+        #[allow(deprecated)]
+        get_instance_packed_len(&order_tracker)?,
+        true,
+    )?;
+    order_tracker.serialize(&mut &mut order_tracker_info.data.borrow_mut()[..])?;
+
+    ProtocolEvent::CircuitBreakerReset {
+        namespace: config.namespace,
+        mint: *mint_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use {
+    crate::{
+        error::ProtocolError,
+        events::ProtocolEvent,
+        instruction::UpdateDenylistInstruction,
+        processor::admin::check_admin,
+        processor::utils::resize_account,
+        state::{Denylist, ProtocolConfig},
+    },
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+fn update_denylist(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    update: impl FnOnce(&mut Denylist) -> ProgramResult,
+) -> Result<ProtocolConfig, ProgramError> {
+    let accounts_iter = &mut accounts.iter();
+
+    // Accounts expected by this instruction:
+    // 0. []    Protocol Config
+    // 1. [w+s] Protocol Admin
+    // 2. [w]   Denylist
+    // 3. []    System Program
+    let config_info = next_account_info(accounts_iter)?;
+    let admin_info = next_account_info(accounts_iter)?;
+    let denylist_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    let config = check_admin(program_id, config_info, admin_info)?;
+
+    // Assert the namespace's denylist was provided.
+    if denylist_info.key != &Denylist::address_with_program_id(&config.namespace, program_id)
+        || denylist_info.owner != program_id
+    {
+        return Err(ProtocolError::IncorrectDenylist.into());
+    }
+
+    let mut denylist = Denylist::try_from_slice(&denylist_info.data.borrow())?;
+    update(&mut denylist)?;
+
+    resize_account(denylist_info, admin_info, denylist.packed_len())?;
+    denylist.serialize(&mut &mut denylist_info.data.borrow_mut()[..])?;
+
+    Ok(config)
+}
+
+pub fn process_add_to_denylist(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: UpdateDenylistInstruction,
+) -> ProgramResult {
+    let config = update_denylist(program_id, accounts, |denylist| {
+        if !denylist.wallets.insert(&data.wallet_address) {
+            return Err(ProtocolError::WalletAlreadyDenylisted.into());
+        }
+        Ok(())
+    })?;
+    ProtocolEvent::WalletDenylisted {
+        namespace: config.namespace,
+        wallet: data.wallet_address,
+    }
+    .emit();
+    Ok(())
+}
+
+pub fn process_remove_from_denylist(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: UpdateDenylistInstruction,
+) -> ProgramResult {
+    let config = update_denylist(program_id, accounts, |denylist| {
+        if !denylist.wallets.remove(&data.wallet_address) {
+            return Err(ProtocolError::WalletNotDenylisted.into());
+        }
+        Ok(())
+    })?;
+    ProtocolEvent::WalletUndenylisted {
+        namespace: config.namespace,
+        wallet: data.wallet_address,
+    }
+    .emit();
+    Ok(())
+}
@@ -3,15 +3,24 @@ use solana_program::borsh0_10::get_instance_packed_len;
 use {
     crate::{
         error::ProtocolError,
-        state::{OrderTracker, Profile, Soulbound},
+        events::{ProtocolEvent, VolumeTotals},
+        state::{
+            Denylist, Exemptions, Lockup, MintActivity, MintRules, OrderTracker, PairVolume,
+            Profile, ProgramFilter, TradingSchedule, TransferHistory, TransferHistoryEntry,
+            TransferPermit, WalletLink,
+        },
     },
     borsh::{BorshDeserialize, BorshSerialize},
     solana_program::{
         account_info::{next_account_info, AccountInfo},
+        clock::Clock,
         entrypoint::ProgramResult,
+        hash::hash,
         program_error::ProgramError,
         pubkey::Pubkey,
+        sysvar::{self, Sysvar},
     },
+    spl_associated_token_account::get_associated_token_address_with_program_id,
     spl_token_2022::{
         extension::{
             transfer_hook::TransferHookAccount, BaseStateWithExtensions, StateWithExtensions,
@@ -27,16 +36,32 @@ fn get_owner_from_token_account(token_account_info: &AccountInfo) -> Result<Pubk
     Ok(token_account.base.owner)
 }
 
-fn check_soulbound_token_account(
+fn get_amount_from_token_account(token_account_info: &AccountInfo) -> Result<u64, ProgramError> {
+    let token_account_data = token_account_info.data.borrow();
+    let token_account = StateWithExtensions::<TokenAccount>::unpack(&token_account_data)?;
+    Ok(token_account.base.amount)
+}
+
+fn get_credential_token_account(owner: &Pubkey, credential_mint: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(owner, credential_mint, &spl_token_2022::id())
+}
+
+fn check_credential_token_account(
     token_account_info: &AccountInfo,
+    credential_mint: &Pubkey,
     expected_owner: &Pubkey,
 ) -> Result<(), ProgramError> {
+    if token_account_info.key != &get_credential_token_account(expected_owner, credential_mint) {
+        return Err(ProtocolError::IncorrectSoulboundTokenAccount.into());
+    }
+    // A wallet that was never issued a credential has no token account.
+    if token_account_info.data_is_empty() {
+        return Err(ProtocolError::SoulboundTokenAccountIsEmpty.into());
+    }
     let token_account_data = token_account_info.data.borrow();
     let token_account = StateWithExtensions::<TokenAccount>::unpack(&token_account_data)?;
     let TokenAccount { amount, owner, .. } = token_account.base;
-    if token_account_info.key != &Soulbound::token_account(expected_owner)
-        || token_account_info.key != &Soulbound::token_account(&owner)
-    {
+    if token_account_info.key != &get_credential_token_account(&owner, credential_mint) {
         return Err(ProtocolError::IncorrectSoulboundTokenAccount.into());
     }
     if amount < 1 {
@@ -45,6 +70,160 @@ fn check_soulbound_token_account(
     Ok(())
 }
 
+/// Resolves a token account owner to the primary wallet it is linked to.
+fn get_primary_wallet(
+    program_id: &Pubkey,
+    link_info: &AccountInfo,
+    namespace: &Pubkey,
+    owner: &Pubkey,
+) -> Result<Pubkey, ProgramError> {
+    if link_info.key != &WalletLink::address_with_program_id(namespace, owner, program_id) {
+        return Err(ProtocolError::IncorrectWalletLink.into());
+    }
+    if link_info.lamports() == 0 {
+        return Err(ProtocolError::WalletLinkNotInitialized.into());
+    }
+    let link = WalletLink::try_from_slice(&link_info.data.borrow())?;
+    Ok(link.primary_wallet)
+}
+
+/// Asserts a party's primary wallet holds the mint's credential and has an
+/// initialized profile, returning the profile.
+fn check_party(
+    credential_token_account_info: &AccountInfo,
+    credential_mint: &Pubkey,
+    profile_info: &AccountInfo,
+    primary: &Pubkey,
+) -> Result<Profile, ProgramError> {
+    check_credential_token_account(credential_token_account_info, credential_mint, primary)?;
+    if profile_info.lamports() == 0 {
+        return Err(ProtocolError::ProfileNotInitialized.into());
+    }
+    Profile::try_from_slice(&profile_info.data.borrow()).map_err(Into::into)
+}
+
+/// Asserts the correct pair volume account was provided and has been
+/// initialized.
+fn check_pair_volume(
+    program_id: &Pubkey,
+    pair_volume_info: &AccountInfo,
+    source_wallet: &Pubkey,
+    destination_wallet: &Pubkey,
+    mint: &Pubkey,
+) -> ProgramResult {
+    if pair_volume_info.key
+        != &PairVolume::address_with_program_id(source_wallet, destination_wallet, mint, program_id)
+    {
+        return Err(ProtocolError::IncorrectPairVolume.into());
+    }
+    if pair_volume_info.lamports() == 0 {
+        return Err(ProtocolError::PairVolumeNotInitialized.into());
+    }
+    Ok(())
+}
+
+/// Records volume sent from one primary wallet to another, returning the
+/// volume sent within the window.
+#[allow(clippy::too_many_arguments)]
+fn record_pair_volume(
+    program_id: &Pubkey,
+    pair_volume_info: &AccountInfo,
+    source_wallet: &Pubkey,
+    destination_wallet: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+    unix_timestamp: i64,
+    window: i64,
+) -> Result<u64, ProgramError> {
+    check_pair_volume(
+        program_id,
+        pair_volume_info,
+        source_wallet,
+        destination_wallet,
+        mint,
+    )?;
+    let mut pair_volume = PairVolume::try_from_slice(&pair_volume_info.data.borrow())?;
+    pair_volume.record(amount, unix_timestamp, window);
+    pair_volume.serialize(&mut &mut pair_volume_info.data.borrow_mut()[..])?;
+    Ok(pair_volume.volume_at(unix_timestamp, window))
+}
+
+/// Reads the volume sent from one primary wallet to another within the
+/// window.
+fn read_pair_volume(
+    program_id: &Pubkey,
+    pair_volume_info: &AccountInfo,
+    source_wallet: &Pubkey,
+    destination_wallet: &Pubkey,
+    mint: &Pubkey,
+    unix_timestamp: i64,
+    window: i64,
+) -> Result<u64, ProgramError> {
+    check_pair_volume(
+        program_id,
+        pair_volume_info,
+        source_wallet,
+        destination_wallet,
+        mint,
+    )?;
+    let pair_volume = PairVolume::try_from_slice(&pair_volume_info.data.borrow())?;
+    Ok(pair_volume.volume_at(unix_timestamp, window))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn consume_transfer_permit(
+    program_id: &Pubkey,
+    permit_info: &AccountInfo,
+    source: &Pubkey,
+    destination: &Pubkey,
+    mint: &Pubkey,
+    approver: &Pubkey,
+    amount: u64,
+    unix_timestamp: i64,
+) -> Result<(), ProgramError> {
+    if permit_info.key
+        != &TransferPermit::address_with_program_id(source, destination, mint, program_id)
+    {
+        return Err(ProtocolError::IncorrectTransferPermit.into());
+    }
+    if permit_info.lamports() == 0 {
+        return Err(ProtocolError::TransferPermitRequired.into());
+    }
+    let mut permit = TransferPermit::try_from_slice(&permit_info.data.borrow())?;
+    // Permits issued by a former approver are no longer valid.
+    if permit.consumed || &permit.approver != approver {
+        return Err(ProtocolError::TransferPermitRequired.into());
+    }
+    if amount > permit.max_amount {
+        return Err(ProtocolError::TransferPermitExceeded.into());
+    }
+    if unix_timestamp > permit.expiry {
+        return Err(ProtocolError::TransferPermitExpired.into());
+    }
+    permit.consumed = true;
+    permit.serialize(&mut &mut permit_info.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+/// Finds the first SPL Memo instruction in the transaction, returning the
+/// hash of its memo.
+fn find_memo_hash(
+    instructions_sysvar_info: &AccountInfo,
+) -> Result<Option<[u8; 32]>, ProgramError> {
+    let mut index = 0;
+    // Loading an instruction past the end of the transaction fails.
+    while let Ok(instruction) =
+        sysvar::instructions::load_instruction_at_checked(index, instructions_sysvar_info)
+    {
+        if instruction.program_id == spl_memo::id() || instruction.program_id == spl_memo::v1::id()
+        {
+            return Ok(Some(hash(&instruction.data).to_bytes()));
+        }
+        index += 1;
+    }
+    Ok(None)
+}
+
 fn check_token_account_is_transferring(account_info: &AccountInfo) -> Result<(), ProgramError> {
     let account_data = account_info.try_borrow_data()?;
     let token_account = StateWithExtensions::<TokenAccount>::unpack(&account_data)?;
@@ -58,7 +237,7 @@ fn check_token_account_is_transferring(account_info: &AccountInfo) -> Result<(),
 
 /// `spl_transfer_hook_interface::execute`
 pub fn process_execute(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     amount: u64,
 ) -> ProgramResult {
@@ -68,80 +247,466 @@ pub fn process_execute(
     // 0. []  Source
     // 1. []  Mint
     // 2. []  Destination
-    // 3. []  Source Owner
+    // 3. []  Transfer Authority (Source Owner or Delegate)
     // 4. []  Validation Account
     // 5. []  Token-2022 Program
     // 6. []  Associated Token Program
-    // 7. []  Soulbound Mint
-    // 8. []  Source Soulbound Token Account
-    // 9. [w] Source Profile
-    // 10. []  Destination Soulbound Token Account
-    // 11. []  Destination Profile
-    // 12. [w] Order Tracker
+    // 7. []  Mint Rules
+    // 8. []  Source Owner Wallet Link
+    // 9. []  Source Primary Wallet Credential Token Account
+    // 10. [w] Source Primary Wallet Profile
+    // 11. []  Destination Owner Wallet Link
+    // 12. []  Destination Primary Wallet Credential Token Account
+    // 13. [w] Destination Primary Wallet Profile
+    // 14. [w] Order Tracker
+    // 15. []  Denylist
+    // 16. []  Exemptions
+    // 17. []  Source Owner Lockup
+    // 18. []  Trading Schedule
+    // 19. []  Transfer Authority Credential Token Account
+    // 20. [w] Transfer Authority Profile
+    // 21. [w] Pair Volume (Source Primary Wallet to Destination Primary Wallet)
+    // 22. []  Pair Volume (Destination Primary Wallet to Source Primary Wallet)
+    // 23. [w] Transfer Permit
+    // 24. []  Instructions Sysvar
+    // 25. []  Program Filter
+    // 26. [w] Transfer History
+    // 27. [w] Source Primary Wallet Mint Activity
     let source_info = next_account_info(accounts_iter)?;
     let mint_info = next_account_info(accounts_iter)?;
     let destination_info = next_account_info(accounts_iter)?;
-    let _source_owner_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
     let _validation_account_info = next_account_info(accounts_iter)?;
     let _token_2022_program_info = next_account_info(accounts_iter)?;
     let _associated_token_program_info = next_account_info(accounts_iter)?;
-    let soulbound_mint_info = next_account_info(accounts_iter)?;
-    let source_soulbound_token_account_info = next_account_info(accounts_iter)?;
+    let mint_rules_info = next_account_info(accounts_iter)?;
+    let source_link_info = next_account_info(accounts_iter)?;
+    let source_credential_token_account_info = next_account_info(accounts_iter)?;
     let source_profile_info = next_account_info(accounts_iter)?;
-    let destination_soulbound_token_account_info = next_account_info(accounts_iter)?;
+    let destination_link_info = next_account_info(accounts_iter)?;
+    let destination_credential_token_account_info = next_account_info(accounts_iter)?;
     let destination_profile_info = next_account_info(accounts_iter)?;
     let order_tracker_info = next_account_info(accounts_iter)?;
+    let denylist_info = next_account_info(accounts_iter)?;
+    let exemptions_info = next_account_info(accounts_iter)?;
+    let lockup_info = next_account_info(accounts_iter)?;
+    let trading_schedule_info = next_account_info(accounts_iter)?;
+    let authority_credential_token_account_info = next_account_info(accounts_iter)?;
+    let authority_profile_info = next_account_info(accounts_iter)?;
+    let pair_volume_info = next_account_info(accounts_iter)?;
+    let reverse_pair_volume_info = next_account_info(accounts_iter)?;
+    let permit_info = next_account_info(accounts_iter)?;
+    let instructions_sysvar_info = next_account_info(accounts_iter)?;
+    let program_filter_info = next_account_info(accounts_iter)?;
+    let transfer_history_info = next_account_info(accounts_iter)?;
+    let mint_activity_info = next_account_info(accounts_iter)?;
+
+    let source_owner = get_owner_from_token_account(source_info)?;
+    let destination_owner = get_owner_from_token_account(destination_info)?;
 
-    // Assert the correct soulbound mint was provided.
-    if soulbound_mint_info.key != &Soulbound::address() {
-        return Err(ProtocolError::IncorrectSoulboundMint.into());
+    // Read the transfer rules set by the mint authority.
+    if mint_rules_info.key != &MintRules::address_with_program_id(mint_info.key, program_id) {
+        return Err(ProtocolError::IncorrectMintRules.into());
     }
+    let rules = MintRules::try_from_slice(&mint_rules_info.data.borrow())?;
 
-    // For the source, assert the correct soulbound token account was provided,
-    // and that the soulbound token account has one token.
-    check_soulbound_token_account(
-        source_soulbound_token_account_info,
-        &get_owner_from_token_account(source_info)?,
-    )?;
+    // Assert the mint is not halted by its circuit breaker. A halted mint
+    // rejects every transfer until the protocol admin resets it.
+    if order_tracker_info.key
+        != &OrderTracker::address_with_program_id(&rules.namespace, program_id)
+    {
+        return Err(ProtocolError::IncorrectOrderTracker.into());
+    }
+    let mut order_tracker = OrderTracker::try_from_slice(&order_tracker_info.data.borrow())?;
+    if order_tracker.is_halted(mint_info.key) {
+        return Err(ProtocolError::MintHalted.into());
+    }
+
+    // Assert the amount is within the mint's single-transfer bounds.
+    if amount < rules.min_amount {
+        return Err(ProtocolError::TransferBelowMinimum.into());
+    }
+    if rules.max_amount != 0 && amount > rules.max_amount {
+        return Err(ProtocolError::TransferAboveMaximum.into());
+    }
 
-    // For the destination, assert the correct soulbound token account was provided,
-    // and that the soulbound token account has one token.
-    check_soulbound_token_account(
-        destination_soulbound_token_account_info,
-        &get_owner_from_token_account(destination_info)?,
+    // Owners exempted by the mint authority, such as pool or escrow
+    // authorities, skip the credential and profile requirements, as does any
+    // side of the transfer the mint's rules do not require a credential for.
+    if exemptions_info.key != &Exemptions::address_with_program_id(mint_info.key, program_id) {
+        return Err(ProtocolError::IncorrectExemptionsAccount.into());
+    }
+    let exemptions = exemptions_info.data.borrow();
+    let exempt = |owner| Exemptions::packed_owners_contains(&exemptions, owner);
+    let source_exempt = !rules.require_source_credential || exempt(&source_owner)?;
+    let destination_exempt = !rules.require_destination_credential || exempt(&destination_owner)?;
+
+    // Assert the destination's post-transfer balance is within the mint's
+    // maximum balance, unless its owner, such as a treasury, is exempt from it.
+    if rules.max_balance != 0
+        && !Exemptions::packed_balance_cap_owners_contains(&exemptions, &destination_owner)?
+        && get_amount_from_token_account(destination_info)? > rules.max_balance
+    {
+        return Err(ProtocolError::DestinationAboveMaximumBalance.into());
+    }
+
+    // Resolve each owner to the primary wallet whose credential it trades
+    // under. Wallets without a profile of their own, such as exempt pool
+    // authorities, are linked to themselves.
+    let source_primary = get_primary_wallet(
+        program_id,
+        source_link_info,
+        &rules.namespace,
+        &source_owner,
+    )?;
+    let destination_primary = get_primary_wallet(
+        program_id,
+        destination_link_info,
+        &rules.namespace,
+        &destination_owner,
     )?;
 
-    // Assert the source owner's profile exists.
-    if source_profile_info.lamports() == 0 {
-        return Err(ProtocolError::ProfileNotInitialized.into());
+    // Assert neither owner, nor the primary wallet it is linked to, is on the
+    // denylist of the mint's namespace.
+    if denylist_info.key != &Denylist::address_with_program_id(&rules.namespace, program_id) {
+        return Err(ProtocolError::IncorrectDenylist.into());
+    }
+    let denylist = denylist_info.data.borrow();
+    let denylisted = |wallet| Denylist::packed_contains(&denylist, wallet);
+    if denylisted(&source_owner)? || denylisted(&source_primary)? {
+        return Err(ProtocolError::SourceOwnerDenylisted.into());
+    }
+    if denylisted(&destination_owner)? || denylisted(&destination_primary)? {
+        return Err(ProtocolError::DestinationOwnerDenylisted.into());
     }
 
-    // Assert the destination owner's profile exists.
-    if destination_profile_info.lamports() == 0 {
-        return Err(ProtocolError::ProfileNotInitialized.into());
+    // For the source, assert the credential token account and profile are
+    // valid, and that the profile has not been frozen by the protocol admin.
+    let mut source_profile = if source_exempt {
+        None
+    } else {
+        let profile = check_party(
+            source_credential_token_account_info,
+            &rules.credential_mint,
+            source_profile_info,
+            &source_primary,
+        )?;
+        if profile.frozen {
+            return Err(ProtocolError::SourceProfileFrozen.into());
+        }
+        Some(profile)
+    };
+
+    // For the destination, assert the credential token account and profile are
+    // valid, and that the profile has not been frozen by the protocol admin.
+    let mut destination_profile = if destination_exempt {
+        None
+    } else {
+        let profile = check_party(
+            destination_credential_token_account_info,
+            &rules.credential_mint,
+            destination_profile_info,
+            &destination_primary,
+        )?;
+        if profile.frozen {
+            return Err(ProtocolError::DestinationProfileFrozen.into());
+        }
+        Some(profile)
+    };
+
+    // If the mint blocks wash trading, assert neither profile is flagged.
+    if rules.block_wash_trading
+        && [&source_profile, &destination_profile]
+            .iter()
+            .any(|profile| profile.as_ref().is_some_and(|p| p.wash_trading_flagged))
+    {
+        return Err(ProtocolError::WashTradingFlagged.into());
     }
 
+    // When a delegate or permanent delegate, other than the source owner's own
+    // primary wallet, transfers on the source owner's behalf, assert the
+    // delegate is not on the denylist, and that it holds its own credential
+    // and profile, unless exempt.
+    let delegated = authority_info.key != &source_owner && authority_info.key != &source_primary;
+    if delegated && denylisted(authority_info.key)? {
+        return Err(ProtocolError::DelegateDenylisted.into());
+    }
+    let delegate_exempt = !rules.require_delegate_credential || exempt(authority_info.key)?;
+    let delegate_profile = if !delegated || delegate_exempt {
+        None
+    } else {
+        let profile = check_party(
+            authority_credential_token_account_info,
+            &rules.credential_mint,
+            authority_profile_info,
+            authority_info.key,
+        )?;
+        if profile.frozen {
+            return Err(ProtocolError::DelegateProfileFrozen.into());
+        }
+        Some(profile)
+    };
+
     // Assert the token accounts are set to transferring.
     // This protects against unwanted invoking of this instruction.
     check_token_account_is_transferring(source_info)?;
     check_token_account_is_transferring(destination_info)?;
 
-    // Update the user's profile volume.
-    let mut profile = Profile::try_from_slice(&source_profile_info.data.borrow())?;
-    profile.order_volume += amount;
-    profile.serialize(&mut &mut source_profile_info.data.borrow_mut()[..])?;
+    let clock = Clock::get()?;
 
-    // Update the order tracker.
-    let mut order_tracker = OrderTracker::try_from_slice(&order_tracker_info.data.borrow())?;
-    order_tracker.increment(mint_info.key, amount);
+    // Assert the mint may be traded at the current time.
+    if trading_schedule_info.key
+        != &TradingSchedule::address_with_program_id(mint_info.key, program_id)
+    {
+        return Err(ProtocolError::IncorrectTradingSchedule.into());
+    }
+    if trading_schedule_info.lamports() != 0 {
+        let schedule = TradingSchedule::try_from_slice(&trading_schedule_info.data.borrow())?;
+        if !schedule.is_open(clock.unix_timestamp) {
+            return Err(ProtocolError::TradingClosed.into());
+        }
+    }
 
-    order_tracker_info.realloc(
-        #[allow(deprecated)]
-        get_instance_packed_len(&order_tracker)?,
-        true,
+    if !sysvar::instructions::check_id(instructions_sysvar_info.key) {
+        return Err(ProtocolError::IncorrectInstructionsSysvar.into());
+    }
+
+    // Assert the transfer was initiated by a top-level program the mint
+    // permits, such as Token-2022 itself or a settlement program.
+    if program_filter_info.key != &ProgramFilter::address_with_program_id(mint_info.key, program_id)
+    {
+        return Err(ProtocolError::IncorrectProgramFilter.into());
+    }
+    if program_filter_info.lamports() != 0 {
+        let filter = ProgramFilter::try_from_slice(&program_filter_info.data.borrow())?;
+        let index = sysvar::instructions::load_current_index_checked(instructions_sysvar_info)?;
+        let instruction = sysvar::instructions::load_instruction_at_checked(
+            index as usize,
+            instructions_sysvar_info,
+        )?;
+        if !filter.permits(&instruction.program_id) {
+            return Err(ProtocolError::ProgramNotPermitted.into());
+        }
+    }
+
+    // While any amount is still locked for the source owner, assert the source
+    // is the owner's associated token account and its remaining balance covers
+    // the locked amount. Restricting the owner to one account keeps it from
+    // sending locked tokens from its other accounts.
+    if lockup_info.key != &Lockup::address_with_program_id(&source_owner, mint_info.key, program_id)
+    {
+        return Err(ProtocolError::IncorrectLockup.into());
+    }
+    if lockup_info.lamports() != 0 {
+        let lockup = Lockup::try_from_slice(&lockup_info.data.borrow())?;
+        let locked = lockup.locked_at(clock.unix_timestamp);
+        if locked != 0 {
+            if source_info.key
+                != &get_associated_token_address_with_program_id(
+                    &source_owner,
+                    mint_info.key,
+                    mint_info.owner,
+                )
+            {
+                return Err(ProtocolError::LockedSourceNotAssociatedTokenAccount.into());
+            }
+            if get_amount_from_token_account(source_info)? < locked {
+                return Err(ProtocolError::TransferExceedsUnlockedBalance.into());
+            }
+        }
+    }
+
+    // Transfers above the mint's permit threshold must be covered by an
+    // unexpired permit from the mint's permit approver, which they consume.
+    if rules.permit_threshold != 0 && amount > rules.permit_threshold {
+        consume_transfer_permit(
+            program_id,
+            permit_info,
+            source_info.key,
+            destination_info.key,
+            mint_info.key,
+            &rules.permit_approver,
+            amount,
+            clock.unix_timestamp,
+        )?;
+    }
+
+    // Transfers above the mint's memo threshold must include a memo, such as
+    // a travel rule reference, in the same transaction.
+    let memo_hash = find_memo_hash(instructions_sysvar_info)?;
+    if rules.memo_threshold != 0 && amount > rules.memo_threshold && memo_hash.is_none() {
+        return Err(ProtocolError::MemoRequired.into());
+    }
+
+    // Assert the mint's cooldown has elapsed since the source profile's last
+    // outgoing transfer of the mint, and that the transfer stays within the
+    // source profile's outflow limit for the mint. Both are tracked under the
+    // primary wallet, across every wallet linked to it. Without a mint
+    // activity account, the source profile has no outflow limit for the mint.
+    if source_profile.is_some() {
+        if mint_activity_info.key
+            != &MintActivity::address_with_program_id(&source_primary, mint_info.key, program_id)
+        {
+            return Err(ProtocolError::IncorrectMintActivity.into());
+        }
+        if mint_activity_info.lamports() != 0 {
+            let mut activity = MintActivity::try_from_slice(&mint_activity_info.data.borrow())?;
+            if rules.transfer_cooldown_slots != 0
+                && activity.last_transfer_slot != 0
+                && clock.slot
+                    < activity
+                        .last_transfer_slot
+                        .saturating_add(rules.transfer_cooldown_slots)
+            {
+                return Err(ProtocolError::TransferCooldownActive.into());
+            }
+            activity.last_transfer_slot = clock.slot;
+            if !activity.record_outflow(amount, clock.unix_timestamp) {
+                return Err(ProtocolError::OutflowLimitExceeded.into());
+            }
+            activity.serialize(&mut &mut mint_activity_info.data.borrow_mut()[..])?;
+        } else if rules.transfer_cooldown_slots != 0 {
+            return Err(ProtocolError::MintActivityNotInitialized.into());
+        }
+    }
+
+    // Transfers between accounts of the same wallet, or of wallets linked to
+    // the same profile, are not trades. They are recorded separately and left
+    // out of the order volume.
+    let self_transfer = source_primary == destination_primary;
+
+    if rules.track_volume && self_transfer {
+        if let Some(profile) = source_profile.as_mut().or(destination_profile.as_mut()) {
+            profile.self_transfer_volume += amount;
+        }
+    } else if rules.track_volume {
+        // Update the user's profile volume. If the source owner is exempt, the
+        // volume is recorded for the destination owner instead.
+        if let Some(profile) = source_profile.as_mut().or(destination_profile.as_mut()) {
+            profile.order_volume += amount;
+        }
+    }
+    let mut new_totals = source_profile
+        .as_ref()
+        .or(destination_profile.as_ref())
+        .map(|profile| VolumeTotals {
+            order_volume: profile.order_volume,
+            self_transfer_volume: profile.self_transfer_volume,
+            ..VolumeTotals::default()
+        })
+        .unwrap_or_default();
+
+    // Update the order tracker's volume and the mint's circuit breaker, if the
+    // protocol admin has set one. The transfer that trips the breaker still
+    // goes through.
+    let circuit_breaker_enabled = order_tracker.circuit_breakers.contains_key(mint_info.key);
+    if !self_transfer && (rules.track_volume || circuit_breaker_enabled) {
+        if rules.track_volume {
+            order_tracker.increment(mint_info.key, amount);
+        }
+        if circuit_breaker_enabled {
+            order_tracker.record_circuit_breaker_volume(mint_info.key, amount, clock.slot);
+        }
+
+        order_tracker_info.realloc(
+            #[allow(deprecated)]
+            get_instance_packed_len(&order_tracker)?,
+            true,
+        )?;
+
+        order_tracker.serialize(&mut &mut order_tracker_info.data.borrow_mut()[..])?;
+    }
+    if rules.track_volume {
+        new_totals.mint_volume = order_tracker
+            .volume
+            .get(mint_info.key)
+            .copied()
+            .unwrap_or_default();
+    }
+
+    // Track the volume between the two primary wallets, flagging both
+    // profiles when enough of what one sent to the other has come back within
+    // the window. Every trade between two profiles is tracked, so the pair's
+    // volume accounts must be initialized before its first transfer.
+    if rules.wash_trade_ratio_bps != 0 && !self_transfer {
+        if let (Some(source), Some(destination)) =
+            (source_profile.as_mut(), destination_profile.as_mut())
+        {
+            let window = rules.wash_trade_window;
+            let sent = record_pair_volume(
+                program_id,
+                pair_volume_info,
+                &source_primary,
+                &destination_primary,
+                mint_info.key,
+                amount,
+                clock.unix_timestamp,
+                window,
+            )?;
+            let returned = read_pair_volume(
+                program_id,
+                reverse_pair_volume_info,
+                &destination_primary,
+                &source_primary,
+                mint_info.key,
+                clock.unix_timestamp,
+                window,
+            )?;
+            let round_trip = sent.min(returned) as u128;
+            let threshold = sent.max(returned) as u128 * rules.wash_trade_ratio_bps as u128;
+            if round_trip != 0 && round_trip * 10_000 >= threshold {
+                source.wash_trading_flagged = true;
+                destination.wash_trading_flagged = true;
+            }
+        }
+    }
+
+    // The destination profile is written first, as it is the same account as
+    // the source profile for a same-profile transfer, in which case only the
+    // source profile's copy was updated.
+    if let Some(profile) = destination_profile {
+        profile.serialize(&mut &mut destination_profile_info.data.borrow_mut()[..])?;
+    }
+    if let Some(profile) = source_profile {
+        profile.serialize(&mut &mut source_profile_info.data.borrow_mut()[..])?;
+    }
+
+    // Add the transfer, along with the hash of its memo, to the mint's recent
+    // transfers.
+    if transfer_history_info.key
+        != &TransferHistory::address_with_program_id(mint_info.key, program_id)
+    {
+        return Err(ProtocolError::IncorrectTransferHistory.into());
+    }
+    TransferHistory::push_packed(
+        &mut transfer_history_info.data.borrow_mut(),
+        &TransferHistoryEntry {
+            slot: clock.slot,
+            source_owner,
+            destination_owner,
+            amount,
+            memo_hash: memo_hash.unwrap_or_default(),
+        },
     )?;
 
-    order_tracker.serialize(&mut &mut order_tracker_info.data.borrow_mut()[..])?;
+    // Record the volume moved by a delegate on its own profile. The profile is
+    // read again, as it may also be the destination's profile.
+    if delegate_profile.is_some() && rules.track_volume {
+        let mut profile = Profile::try_from_slice(&authority_profile_info.data.borrow())?;
+        profile.delegated_volume += amount;
+        profile.serialize(&mut &mut authority_profile_info.data.borrow_mut()[..])?;
+    }
+
+    ProtocolEvent::TransferRecorded {
+        mint: *mint_info.key,
+        source: *source_info.key,
+        destination: *destination_info.key,
+        source_owner,
+        destination_owner,
+        amount,
+        new_totals,
+    }
+    .emit();
 
     Ok(())
 }
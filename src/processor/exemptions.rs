@@ -0,0 +1,141 @@
+use {
+    crate::{
+        error::ProtocolError,
+        events::ProtocolEvent,
+        instruction::UpdateExemptionsInstruction,
+        processor::utils::{check_mint_authority, resize_account},
+        state::Exemptions,
+    },
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+/// Applies an update to a protocol mint's exemptions, returning the mint's
+/// address.
+fn update_exemptions(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    update: impl FnOnce(&mut Exemptions) -> ProgramResult,
+) -> Result<Pubkey, ProgramError> {
+    let accounts_iter = &mut accounts.iter();
+
+    // Accounts expected by this instruction:
+    // 0. []    Mint
+    // 1. [w+s] Mint Authority
+    // 2. [w]   Exemptions
+    // 3. []    System Program
+    let mint_info = next_account_info(accounts_iter)?;
+    let mint_authority_info = next_account_info(accounts_iter)?;
+    let exemptions_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    check_mint_authority(mint_info, mint_authority_info)?;
+
+    // Assert the correct exemptions account was provided.
+    if exemptions_info.key != &Exemptions::address_with_program_id(mint_info.key, program_id)
+        || exemptions_info.owner != program_id
+    {
+        return Err(ProtocolError::IncorrectExemptionsAccount.into());
+    }
+
+    let mut exemptions = Exemptions::try_from_slice(&exemptions_info.data.borrow())?;
+    update(&mut exemptions)?;
+
+    resize_account(
+        exemptions_info,
+        mint_authority_info,
+        exemptions.packed_len(),
+    )?;
+    exemptions.serialize(&mut &mut exemptions_info.data.borrow_mut()[..])?;
+
+    Ok(*mint_info.key)
+}
+
+pub fn process_add_exemption(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: UpdateExemptionsInstruction,
+) -> ProgramResult {
+    let mint = update_exemptions(program_id, accounts, |exemptions| {
+        if !exemptions.owners.insert(&data.owner_address) {
+            return Err(ProtocolError::OwnerAlreadyExempt.into());
+        }
+        Ok(())
+    })?;
+
+    ProtocolEvent::ExemptionAdded {
+        mint,
+        owner: data.owner_address,
+    }
+    .emit();
+
+    Ok(())
+}
+
+pub fn process_remove_exemption(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: UpdateExemptionsInstruction,
+) -> ProgramResult {
+    let mint = update_exemptions(program_id, accounts, |exemptions| {
+        if !exemptions.owners.remove(&data.owner_address) {
+            return Err(ProtocolError::OwnerNotExempt.into());
+        }
+        Ok(())
+    })?;
+
+    ProtocolEvent::ExemptionRemoved {
+        mint,
+        owner: data.owner_address,
+    }
+    .emit();
+
+    Ok(())
+}
+
+pub fn process_add_balance_cap_exemption(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: UpdateExemptionsInstruction,
+) -> ProgramResult {
+    let mint = update_exemptions(program_id, accounts, |exemptions| {
+        if !exemptions.balance_cap_owners.insert(&data.owner_address) {
+            return Err(ProtocolError::OwnerAlreadyExempt.into());
+        }
+        Ok(())
+    })?;
+
+    ProtocolEvent::BalanceCapExemptionAdded {
+        mint,
+        owner: data.owner_address,
+    }
+    .emit();
+
+    Ok(())
+}
+
+pub fn process_remove_balance_cap_exemption(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: UpdateExemptionsInstruction,
+) -> ProgramResult {
+    let mint = update_exemptions(program_id, accounts, |exemptions| {
+        if !exemptions.balance_cap_owners.remove(&data.owner_address) {
+            return Err(ProtocolError::OwnerNotExempt.into());
+        }
+        Ok(())
+    })?;
+
+    ProtocolEvent::BalanceCapExemptionRemoved {
+        mint,
+        owner: data.owner_address,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use {
+    crate::{
+        error::ProtocolError,
+        events::ProtocolEvent,
+        instruction::SetProgramFilterInstruction,
+        processor::utils::{check_mint_authority, resize_account},
+        state::ProgramFilter,
+    },
+    borsh::BorshSerialize,
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        program::invoke_signed,
+        pubkey::Pubkey,
+    },
+};
+
+pub fn process_set_program_filter(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: SetProgramFilterInstruction,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Accounts expected by this instruction:
+    // 0. []    Mint
+    // 1. [w+s] Mint Authority
+    // 2. [w]   Program Filter
+    // 3. []    System Program
+    let mint_info = next_account_info(accounts_iter)?;
+    let mint_authority_info = next_account_info(accounts_iter)?;
+    let filter_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    check_mint_authority(mint_info, mint_authority_info)?;
+
+    let (filter_address, bump) =
+        ProgramFilter::address_with_bump_and_program_id(mint_info.key, program_id);
+    if filter_info.key != &filter_address {
+        return Err(ProtocolError::IncorrectProgramFilter.into());
+    }
+
+    // Create the filter account if it does not exist yet.
+    if filter_info.lamports() == 0 {
+        let signer_seeds = &[ProgramFilter::seed(), mint_info.key.as_ref(), &[bump]];
+        invoke_signed(
+            &ProgramFilter::create_account_instruction(
+                mint_authority_info.key,
+                mint_info.key,
+                program_id,
+            ),
+            &[filter_info.clone(), mint_authority_info.clone()],
+            &[signer_seeds],
+        )?;
+    } else if filter_info.owner != program_id {
+        return Err(ProtocolError::IncorrectProgramFilter.into());
+    }
+
+    // Replace the filter's mode and programs.
+    let mut filter = ProgramFilter {
+        mode: data.mode.clone(),
+        ..ProgramFilter::default()
+    };
+    for program_id in data.program_ids.iter() {
+        filter.program_ids.insert(program_id);
+    }
+
+    resize_account(filter_info, mint_authority_info, filter.packed_len())?;
+    filter.serialize(&mut &mut filter_info.data.borrow_mut()[..])?;
+
+    ProtocolEvent::ProgramFilterSet {
+        mint: *mint_info.key,
+        mode: data.mode,
+        program_ids: data.program_ids,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -1,46 +1,185 @@
 use {
-    crate::state::{OrderTracker, Soulbound},
+    crate::{
+        error::ProtocolError,
+        events::ProtocolEvent,
+        state::{
+            get_program_data_address, Denylist, MintAuthority, OrderTracker, ProtocolConfig,
+            Soulbound, DEFAULT_NAMESPACE,
+        },
+    },
     borsh::BorshSerialize,
     solana_program::{
         account_info::{next_account_info, AccountInfo},
+        bpf_loader_upgradeable::{self, UpgradeableLoaderState},
         entrypoint::ProgramResult,
-        program::invoke,
+        program::{invoke, invoke_signed},
         program_error::ProgramError,
         pubkey::Pubkey,
     },
 };
 
-pub fn process_init(_program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+/// Asserts the account is the program's data account, naming the signer as
+/// the program's upgrade authority.
+fn check_upgrade_authority(
+    program_id: &Pubkey,
+    program_data_info: &AccountInfo,
+    authority_info: &AccountInfo,
+) -> ProgramResult {
+    if program_data_info.key != &get_program_data_address(program_id)
+        || program_data_info.owner != &bpf_loader_upgradeable::id()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let data = program_data_info.try_borrow_data()?;
+    let metadata = data
+        .get(..UpgradeableLoaderState::size_of_programdata_metadata())
+        .ok_or(ProgramError::InvalidAccountData)?;
+    match bincode::deserialize(metadata) {
+        Ok(UpgradeableLoaderState::ProgramData {
+            upgrade_authority_address: Some(upgrade_authority),
+            ..
+        }) if &upgrade_authority == authority_info.key && authority_info.is_signer => Ok(()),
+        Ok(UpgradeableLoaderState::ProgramData { .. }) => {
+            Err(ProtocolError::IncorrectUpgradeAuthority.into())
+        }
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+pub fn process_init(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    namespace: &Pubkey,
+) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
     // Accounts expected by this instruction:
     //
     // 0. [w]   Soulbound Mint
     // 1. [w]   Order Tracker
-    // 2. [w+s] Payer
-    // 3. []    Token-2022 Program
+    // 2. [w]   Protocol Config
+    // 3. [w]   Denylist
+    // 4. [w+s] Payer
+    // 5. []    Token-2022 Program
+    // 6. []    System Program
+    // 7. [s]   Namespace, or the program's data account for the default
+    //          namespace
+    // 8. [w]   Soulbound Mint Authority
     let soulbound_mint_info = next_account_info(accounts_iter)?;
     let order_tracker_info = next_account_info(accounts_iter)?;
+    let config_info = next_account_info(accounts_iter)?;
+    let denylist_info = next_account_info(accounts_iter)?;
     let payer_info = next_account_info(accounts_iter)?;
     let _token_2022_program_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+    let namespace_info = next_account_info(accounts_iter)?;
+    let soulbound_mint_authority_info = next_account_info(accounts_iter)?;
 
     if !payer_info.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
     }
 
+    // Assert the payer may claim the namespace, so no one can take over
+    // another tenant's namespace by initializing it first. A namespace is
+    // claimed by signing with its key, while the default namespace, which has
+    // no key, may only be claimed by the program's upgrade authority.
+    if namespace == &DEFAULT_NAMESPACE {
+        check_upgrade_authority(program_id, namespace_info, payer_info)?;
+    } else {
+        if namespace_info.key != namespace {
+            return Err(ProtocolError::IncorrectNamespace.into());
+        }
+        if !namespace_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+    }
+
     // Create the soulbound mint.
+    {
+        let seed = Soulbound::seed();
+        let bump = Soulbound::address_with_bump_and_program_id(namespace, program_id).1;
+        let signer_seeds = &[seed, namespace.as_ref(), &[bump]];
+        invoke_signed(
+            &Soulbound::create_account_instruction(payer_info.key, namespace, program_id),
+            &[soulbound_mint_info.clone(), payer_info.clone()],
+            &[signer_seeds],
+        )?;
+    }
+    invoke(
+        &Soulbound::initialize_non_transferrable_instruction(namespace, program_id),
+        &[soulbound_mint_info.clone(), payer_info.clone()],
+    )?;
     invoke(
-        &Soulbound::initialize_non_transferrable_instruction(),
+        &Soulbound::initialize_permanent_delegate_instruction(namespace, program_id),
         &[soulbound_mint_info.clone(), payer_info.clone()],
     )?;
     invoke(
-        &Soulbound::initialize_mint_instruction(),
+        &Soulbound::initialize_mint_instruction(namespace, program_id),
         &[soulbound_mint_info.clone(), payer_info.clone()],
     )?;
 
+    // Create the soulbound mint authority's account.
+    {
+        let seed = MintAuthority::seed();
+        let bump = MintAuthority::address_with_bump_and_program_id(namespace, program_id).1;
+        let signer_seeds = &[seed, namespace.as_ref(), &[bump]];
+        invoke_signed(
+            &MintAuthority::create_account_instruction(payer_info.key, namespace, program_id),
+            &[soulbound_mint_authority_info.clone(), payer_info.clone()],
+            &[signer_seeds],
+        )?;
+    }
+
     // Create the order tracker.
+    {
+        let seed = OrderTracker::seed();
+        let bump = OrderTracker::address_with_bump_and_program_id(namespace, program_id).1;
+        let signer_seeds = &[seed, namespace.as_ref(), &[bump]];
+        invoke_signed(
+            &OrderTracker::create_account_instruction(payer_info.key, namespace, program_id),
+            &[order_tracker_info.clone(), payer_info.clone()],
+            &[signer_seeds],
+        )?;
+    }
+
     let order_tracker = OrderTracker::default();
     order_tracker.serialize(&mut &mut order_tracker_info.data.borrow_mut()[..])?;
 
+    // Create the protocol config, with the payer as the admin.
+    {
+        let seed = ProtocolConfig::seed();
+        let bump = ProtocolConfig::address_with_bump_and_program_id(namespace, program_id).1;
+        let signer_seeds = &[seed, namespace.as_ref(), &[bump]];
+        invoke_signed(
+            &ProtocolConfig::create_account_instruction(payer_info.key, namespace, program_id),
+            &[config_info.clone(), payer_info.clone()],
+            &[signer_seeds],
+        )?;
+    }
+
+    let config = ProtocolConfig::new(payer_info.key, namespace);
+    config.serialize(&mut &mut config_info.data.borrow_mut()[..])?;
+
+    // Create the empty denylist.
+    {
+        let seed = Denylist::seed();
+        let bump = Denylist::address_with_bump_and_program_id(namespace, program_id).1;
+        let signer_seeds = &[seed, namespace.as_ref(), &[bump]];
+        invoke_signed(
+            &Denylist::create_account_instruction(payer_info.key, namespace, program_id),
+            &[denylist_info.clone(), payer_info.clone()],
+            &[signer_seeds],
+        )?;
+    }
+
+    let denylist = Denylist::default();
+    denylist.serialize(&mut &mut denylist_info.data.borrow_mut()[..])?;
+
+    ProtocolEvent::ProtocolInitialized {
+        namespace: *namespace,
+        admin: *payer_info.key,
+    }
+    .emit();
+
     Ok(())
 }
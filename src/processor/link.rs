@@ -0,0 +1,218 @@
+use {
+    crate::{
+        error::ProtocolError,
+        events::ProtocolEvent,
+        state::{Profile, WalletLink},
+    },
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        program::invoke_signed,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+/// Writes a link from a wallet to its primary wallet, creating the link
+/// account if it does not exist yet.
+pub(crate) fn write_wallet_link<'a>(
+    program_id: &Pubkey,
+    link_info: &AccountInfo<'a>,
+    payer_info: &AccountInfo<'a>,
+    namespace: &Pubkey,
+    wallet_address: &Pubkey,
+    primary_wallet: &Pubkey,
+) -> ProgramResult {
+    if link_info.key != &WalletLink::address_with_program_id(namespace, wallet_address, program_id)
+    {
+        return Err(ProtocolError::IncorrectWalletLink.into());
+    }
+
+    if link_info.lamports() == 0 {
+        let seed = WalletLink::seed();
+        let bump =
+            WalletLink::address_with_bump_and_program_id(namespace, wallet_address, program_id).1;
+        let signer_seeds = &[seed, namespace.as_ref(), wallet_address.as_ref(), &[bump]];
+        invoke_signed(
+            &WalletLink::create_account_instruction(
+                payer_info.key,
+                namespace,
+                wallet_address,
+                program_id,
+            ),
+            &[link_info.clone(), payer_info.clone()],
+            &[signer_seeds],
+        )?;
+    }
+
+    let link = WalletLink::new(primary_wallet);
+    link.serialize(&mut &mut link_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+/// Asserts a wallet is not linked to another wallet's profile. A wallet
+/// linked to itself, as any wallet without a profile may be, may still take a
+/// profile of its own or be linked to a primary wallet.
+pub(crate) fn check_wallet_not_linked(
+    link_info: &AccountInfo,
+    wallet_address: &Pubkey,
+) -> ProgramResult {
+    if link_info.lamports() != 0 {
+        let link = WalletLink::try_from_slice(&link_info.data.borrow())?;
+        if &link.primary_wallet != wallet_address {
+            return Err(ProtocolError::WalletAlreadyLinked.into());
+        }
+    }
+    Ok(())
+}
+
+pub fn process_initialize_wallet_link(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    namespace: &Pubkey,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Accounts expected by this instruction:
+    // 0. [w+s] Payer
+    // 1. []    Wallet
+    // 2. [w]   Wallet Link
+    // 3. []    System Program
+    let payer_info = next_account_info(accounts_iter)?;
+    let wallet_info = next_account_info(accounts_iter)?;
+    let link_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if link_info.key != &WalletLink::address_with_program_id(namespace, wallet_info.key, program_id)
+    {
+        return Err(ProtocolError::IncorrectWalletLink.into());
+    }
+
+    // An existing link, whether to the wallet itself or to a primary wallet,
+    // is left as it is.
+    if link_info.lamports() != 0 {
+        return Ok(());
+    }
+
+    // A self-link can't keep the wallet from initializing a profile or being
+    // linked to a primary wallet later, so anyone may create it.
+    write_wallet_link(
+        program_id,
+        link_info,
+        payer_info,
+        namespace,
+        wallet_info.key,
+        wallet_info.key,
+    )?;
+
+    ProtocolEvent::WalletLinkInitialized {
+        namespace: *namespace,
+        wallet: *wallet_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
+
+pub fn process_link_wallet(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    namespace: &Pubkey,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Accounts expected by this instruction:
+    // 0. [w]   Primary Profile
+    // 1. [w+s] Primary Wallet
+    // 2. []    Secondary Profile
+    // 3. [s]   Secondary Wallet
+    // 4. [w]   Secondary Wallet Link
+    // 5. []    System Program
+    // 6. []    Primary Wallet Link
+    let primary_profile_info = next_account_info(accounts_iter)?;
+    let primary_wallet_info = next_account_info(accounts_iter)?;
+    let secondary_profile_info = next_account_info(accounts_iter)?;
+    let secondary_wallet_info = next_account_info(accounts_iter)?;
+    let secondary_link_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+    let primary_link_info = next_account_info(accounts_iter)?;
+
+    // Assert both wallets are signers.
+    if !primary_wallet_info.is_signer || !secondary_wallet_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Assert the primary wallet's profile exists.
+    if primary_profile_info.key
+        != &Profile::address_with_program_id(namespace, primary_wallet_info.key, program_id)
+        || primary_profile_info.lamports() == 0
+        || primary_profile_info.owner != program_id
+    {
+        return Err(ProtocolError::ProfileNotInitialized.into());
+    }
+
+    // Assert the primary wallet's profile is neither frozen nor flagged, so a
+    // restricted user can't keep trading through a fresh wallet.
+    let mut primary_profile = Profile::try_from_slice(&primary_profile_info.data.borrow())?;
+    if primary_profile.frozen {
+        return Err(ProtocolError::ProfileFrozen.into());
+    }
+    if primary_profile.wash_trading_flagged {
+        return Err(ProtocolError::WashTradingFlagged.into());
+    }
+
+    // Assert the primary wallet is not itself linked to another profile, as
+    // links are not followed transitively.
+    if primary_link_info.key
+        != &WalletLink::address_with_program_id(namespace, primary_wallet_info.key, program_id)
+    {
+        return Err(ProtocolError::IncorrectWalletLink.into());
+    }
+    check_wallet_not_linked(primary_link_info, primary_wallet_info.key)?;
+
+    // Assert the secondary wallet does not have its own profile, and is not
+    // already linked to a profile.
+    if secondary_profile_info.key
+        != &Profile::address_with_program_id(namespace, secondary_wallet_info.key, program_id)
+    {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if secondary_profile_info.lamports() != 0 {
+        return Err(ProtocolError::WalletHasProfile.into());
+    }
+    if secondary_link_info.key
+        != &WalletLink::address_with_program_id(namespace, secondary_wallet_info.key, program_id)
+    {
+        return Err(ProtocolError::IncorrectWalletLink.into());
+    }
+    check_wallet_not_linked(secondary_link_info, secondary_wallet_info.key)?;
+
+    // Link the secondary wallet to the primary wallet's profile, which the
+    // transfer hook records its volume on, and count the link, so a recovery
+    // of the profile can move every link with it.
+    write_wallet_link(
+        program_id,
+        secondary_link_info,
+        primary_wallet_info,
+        namespace,
+        secondary_wallet_info.key,
+        primary_wallet_info.key,
+    )?;
+    primary_profile.linked_wallets += 1;
+    primary_profile.serialize(&mut &mut primary_profile_info.data.borrow_mut()[..])?;
+
+    ProtocolEvent::WalletLinked {
+        namespace: *namespace,
+        primary_wallet: *primary_wallet_info.key,
+        secondary_wallet: *secondary_wallet_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
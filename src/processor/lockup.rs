@@ -0,0 +1,77 @@
+use {
+    crate::{
+        error::ProtocolError, events::ProtocolEvent, processor::utils::check_mint_authority,
+        state::Lockup,
+    },
+    borsh::BorshSerialize,
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        program::invoke_signed,
+        pubkey::Pubkey,
+    },
+};
+
+pub fn process_set_lockup(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    lockup: Lockup,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Accounts expected by this instruction:
+    // 0. []    Mint
+    // 1. [w+s] Mint Authority
+    // 2. [w]   Lockup
+    // 3. []    Wallet
+    // 4. []    System Program
+    let mint_info = next_account_info(accounts_iter)?;
+    let mint_authority_info = next_account_info(accounts_iter)?;
+    let lockup_info = next_account_info(accounts_iter)?;
+    let wallet_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    check_mint_authority(mint_info, mint_authority_info)?;
+
+    if lockup.unlock_end < lockup.unlock_start {
+        return Err(ProtocolError::InvalidUnlockSchedule.into());
+    }
+
+    // Assert the correct lockup account was provided.
+    let (lockup_address, bump) =
+        Lockup::address_with_bump_and_program_id(wallet_info.key, mint_info.key, program_id);
+    if lockup_info.key != &lockup_address {
+        return Err(ProtocolError::IncorrectLockup.into());
+    }
+
+    if lockup_info.lamports() == 0 {
+        let signer_seeds = &[
+            Lockup::seed(),
+            wallet_info.key.as_ref(),
+            mint_info.key.as_ref(),
+            &[bump],
+        ];
+        invoke_signed(
+            &Lockup::create_account_instruction(
+                mint_authority_info.key,
+                wallet_info.key,
+                mint_info.key,
+                program_id,
+            ),
+            &[lockup_info.clone(), mint_authority_info.clone()],
+            &[signer_seeds],
+        )?;
+    } else if lockup_info.owner != program_id {
+        return Err(ProtocolError::IncorrectLockup.into());
+    }
+
+    lockup.serialize(&mut &mut lockup_info.data.borrow_mut()[..])?;
+
+    ProtocolEvent::LockupSet {
+        mint: *mint_info.key,
+        wallet: *wallet_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
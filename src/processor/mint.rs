@@ -1,11 +1,21 @@
+#[allow(deprecated)]
+use solana_program::borsh0_10::get_instance_packed_len;
 use {
     crate::{
-        error::ProtocolError, instruction::CreateMintInstruction, state::validation::ValidationData,
+        error::ProtocolError,
+        events::ProtocolEvent,
+        instruction::CreateMintInstruction,
+        processor::{
+            admin::check_admin,
+            utils::{check_credential_mint, resize_account},
+        },
+        state::{validation::ValidationData, Exemptions, MintRules, OrderTracker, TransferHistory},
     },
+    borsh::{BorshDeserialize, BorshSerialize},
     solana_program::{
         account_info::{next_account_info, AccountInfo},
         entrypoint::ProgramResult,
-        program::invoke,
+        program::{invoke, invoke_signed},
         program_error::ProgramError,
         pubkey::Pubkey,
     },
@@ -22,18 +32,35 @@ pub fn process_create_mint(
     // Accounts expected by this instruction:
     // 0. [w]   Mint
     // 1. [w]   Validation Account
-    // 2. [s]   Mint Authority
+    // 2. [w+s] Mint Authority
     // 3. []    Token-2022 Program
+    // 4. [w]   Exemptions
+    // 5. []    System Program
+    // 6. [w]   Mint Rules
+    // 7. []    Credential Mint
+    // 8. [w]   Transfer History
+    // 9. []    Protocol Config
+    // 10. [s]  Protocol Admin
+    // 11. [w]  Order Tracker
     let mint_info = next_account_info(accounts_iter)?;
     let validation_info = next_account_info(accounts_iter)?;
     let mint_authority_info = next_account_info(accounts_iter)?;
     let _token_2022_program_info = next_account_info(accounts_iter)?;
+    let exemptions_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+    let mint_rules_info = next_account_info(accounts_iter)?;
+    let credential_mint_info = next_account_info(accounts_iter)?;
+    let transfer_history_info = next_account_info(accounts_iter)?;
+    let config_info = next_account_info(accounts_iter)?;
+    let admin_info = next_account_info(accounts_iter)?;
+    let order_tracker_info = next_account_info(accounts_iter)?;
 
     let CreateMintInstruction {
         decimals,
         name,
         symbol,
         uri,
+        rules,
     } = data;
 
     // Assert the proper validation account was provided.
@@ -46,6 +73,16 @@ pub fn process_create_mint(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
+    // Assert the namespace named by the mint's rules has been initialized, and
+    // its admin approves the mint joining it.
+    let config = check_admin(program_id, config_info, admin_info)?;
+    if config.namespace != rules.namespace {
+        return Err(ProtocolError::IncorrectNamespace.into());
+    }
+
+    // Assert the credential gating the mint is a non-transferable mint.
+    check_credential_mint(credential_mint_info, &rules)?;
+
     // Create the mint.
     // Extensions:
     // - Transfer Hook
@@ -56,7 +93,7 @@ pub fn process_create_mint(
             &spl_token_2022::id(),
             mint_info.key,
             None,
-            Some(crate::id()),
+            Some(*program_id),
         )
         .unwrap(),
         &[mint_info.clone()],
@@ -100,5 +137,84 @@ pub fn process_create_mint(
     // Create the validation data.
     ValidationData::write_validation_data(&mut validation_info.try_borrow_mut_data()?)?;
 
+    // Create the mint's empty exemptions list.
+    {
+        let seed = Exemptions::seed();
+        let bump = Exemptions::address_with_bump_and_program_id(mint_info.key, program_id).1;
+        let signer_seeds = &[seed, mint_info.key.as_ref(), &[bump]];
+        invoke_signed(
+            &Exemptions::create_account_instruction(
+                mint_authority_info.key,
+                mint_info.key,
+                program_id,
+            ),
+            &[exemptions_info.clone(), mint_authority_info.clone()],
+            &[signer_seeds],
+        )?;
+    }
+
+    let exemptions = Exemptions::default();
+    exemptions.serialize(&mut &mut exemptions_info.data.borrow_mut()[..])?;
+
+    // Create the mint's transfer rules.
+    {
+        let seed = MintRules::seed();
+        let bump = MintRules::address_with_bump_and_program_id(mint_info.key, program_id).1;
+        let signer_seeds = &[seed, mint_info.key.as_ref(), &[bump]];
+        invoke_signed(
+            &MintRules::create_account_instruction(
+                mint_authority_info.key,
+                mint_info.key,
+                program_id,
+            ),
+            &[mint_rules_info.clone(), mint_authority_info.clone()],
+            &[signer_seeds],
+        )?;
+    }
+
+    rules.serialize(&mut &mut mint_rules_info.data.borrow_mut()[..])?;
+
+    // Create the mint's empty transfer history. The new account's zeroed data
+    // is already an empty history.
+    {
+        let seed = TransferHistory::seed();
+        let bump = TransferHistory::address_with_bump_and_program_id(mint_info.key, program_id).1;
+        let signer_seeds = &[seed, mint_info.key.as_ref(), &[bump]];
+        invoke_signed(
+            &TransferHistory::create_account_instruction(
+                mint_authority_info.key,
+                mint_info.key,
+                program_id,
+            ),
+            &[transfer_history_info.clone(), mint_authority_info.clone()],
+            &[signer_seeds],
+        )?;
+    }
+
+    // Register the mint in the namespace's order tracker, with the mint
+    // authority paying for its entry.
+    if order_tracker_info.key
+        != &OrderTracker::address_with_program_id(&config.namespace, program_id)
+        || order_tracker_info.owner != program_id
+    {
+        return Err(ProtocolError::IncorrectOrderTracker.into());
+    }
+    let mut order_tracker = OrderTracker::try_from_slice(&order_tracker_info.data.borrow())?;
+    order_tracker.register(mint_info.key);
+    resize_account(
+        order_tracker_info,
+        mint_authority_info,
+        #[allow(deprecated)]
+        get_instance_packed_len(&order_tracker)?,
+    )?;
+    order_tracker.serialize(&mut &mut order_tracker_info.data.borrow_mut()[..])?;
+
+    ProtocolEvent::MintCreated {
+        mint: *mint_info.key,
+        mint_authority: *mint_authority_info.key,
+        namespace: rules.namespace,
+    }
+    .emit();
+
     Ok(())
 }
@@ -1,7 +1,20 @@
+mod activity;
+mod admin;
+mod denylist;
 mod execute;
+mod exemptions;
+mod filter;
 mod init;
+mod link;
+mod lockup;
 mod mint;
+mod pair;
+mod permit;
 mod profile;
+mod recover;
+mod rules;
+mod schedule;
+mod utils;
 
 use {
     crate::instruction::ProtocolInstruction,
@@ -15,17 +28,109 @@ use {
 pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
     if let Ok(instruction) = ProtocolInstruction::unpack(input) {
         match instruction {
-            ProtocolInstruction::InitializeProtocol => {
+            ProtocolInstruction::InitializeProtocol(data) => {
                 msg!("Instruction: InitializeProtocol");
-                init::process_init(program_id, accounts)
+                init::process_init(program_id, accounts, &data.namespace)
             }
             ProtocolInstruction::CreateMint(data) => {
                 msg!("Instruction: CreateMint");
                 mint::process_create_mint(program_id, accounts, data)
             }
-            ProtocolInstruction::InitializeProfile => {
+            ProtocolInstruction::InitializeProfile(data) => {
                 msg!("Instruction: InitializeProfile");
-                profile::process_initialize_profile(program_id, accounts)
+                profile::process_initialize_profile(program_id, accounts, &data.namespace)
+            }
+            ProtocolInstruction::FreezeProfile => {
+                msg!("Instruction: FreezeProfile");
+                admin::process_freeze_profile(program_id, accounts)
+            }
+            ProtocolInstruction::ThawProfile => {
+                msg!("Instruction: ThawProfile");
+                admin::process_thaw_profile(program_id, accounts)
+            }
+            ProtocolInstruction::AddToDenylist(data) => {
+                msg!("Instruction: AddToDenylist");
+                denylist::process_add_to_denylist(program_id, accounts, data)
+            }
+            ProtocolInstruction::RemoveFromDenylist(data) => {
+                msg!("Instruction: RemoveFromDenylist");
+                denylist::process_remove_from_denylist(program_id, accounts, data)
+            }
+            ProtocolInstruction::AddExemption(data) => {
+                msg!("Instruction: AddExemption");
+                exemptions::process_add_exemption(program_id, accounts, data)
+            }
+            ProtocolInstruction::RemoveExemption(data) => {
+                msg!("Instruction: RemoveExemption");
+                exemptions::process_remove_exemption(program_id, accounts, data)
+            }
+            ProtocolInstruction::LinkWallet(data) => {
+                msg!("Instruction: LinkWallet");
+                link::process_link_wallet(program_id, accounts, &data.namespace)
+            }
+            ProtocolInstruction::RecoverProfile => {
+                msg!("Instruction: RecoverProfile");
+                recover::process_recover_profile(program_id, accounts)
+            }
+            ProtocolInstruction::UpdateMintRules(rules) => {
+                msg!("Instruction: UpdateMintRules");
+                rules::process_update_mint_rules(program_id, accounts, rules)
+            }
+            ProtocolInstruction::SetOutflowLimit(data) => {
+                msg!("Instruction: SetOutflowLimit");
+                admin::process_set_outflow_limit(program_id, accounts, data.outflow_limit)
+            }
+            ProtocolInstruction::SetLockup(lockup) => {
+                msg!("Instruction: SetLockup");
+                lockup::process_set_lockup(program_id, accounts, lockup)
+            }
+            ProtocolInstruction::SetTradingWindows(data) => {
+                msg!("Instruction: SetTradingWindows");
+                schedule::process_set_trading_windows(program_id, accounts, data.windows)
+            }
+            ProtocolInstruction::SetTradingOverride(data) => {
+                msg!("Instruction: SetTradingOverride");
+                schedule::process_set_trading_override(program_id, accounts, data.trading_override)
+            }
+            ProtocolInstruction::InitializePairVolume => {
+                msg!("Instruction: InitializePairVolume");
+                pair::process_initialize_pair_volume(program_id, accounts)
+            }
+            ProtocolInstruction::ClearWashTradingFlag => {
+                msg!("Instruction: ClearWashTradingFlag");
+                admin::process_clear_wash_trading_flag(program_id, accounts)
+            }
+            ProtocolInstruction::IssueTransferPermit(data) => {
+                msg!("Instruction: IssueTransferPermit");
+                permit::process_issue_transfer_permit(program_id, accounts, data)
+            }
+            ProtocolInstruction::ResetCircuitBreaker => {
+                msg!("Instruction: ResetCircuitBreaker");
+                admin::process_reset_circuit_breaker(program_id, accounts)
+            }
+            ProtocolInstruction::SetProgramFilter(data) => {
+                msg!("Instruction: SetProgramFilter");
+                filter::process_set_program_filter(program_id, accounts, data)
+            }
+            ProtocolInstruction::AddBalanceCapExemption(data) => {
+                msg!("Instruction: AddBalanceCapExemption");
+                exemptions::process_add_balance_cap_exemption(program_id, accounts, data)
+            }
+            ProtocolInstruction::RemoveBalanceCapExemption(data) => {
+                msg!("Instruction: RemoveBalanceCapExemption");
+                exemptions::process_remove_balance_cap_exemption(program_id, accounts, data)
+            }
+            ProtocolInstruction::InitializeMintActivity => {
+                msg!("Instruction: InitializeMintActivity");
+                activity::process_initialize_mint_activity(program_id, accounts)
+            }
+            ProtocolInstruction::InitializeWalletLink(data) => {
+                msg!("Instruction: InitializeWalletLink");
+                link::process_initialize_wallet_link(program_id, accounts, &data.namespace)
+            }
+            ProtocolInstruction::SetCircuitBreaker(data) => {
+                msg!("Instruction: SetCircuitBreaker");
+                admin::process_set_circuit_breaker(program_id, accounts, data)
             }
         }
     } else if let Ok(instruction) = TransferHookInstruction::unpack(input) {
@@ -0,0 +1,108 @@
+use {
+    crate::{error::ProtocolError, events::ProtocolEvent, state::PairVolume},
+    borsh::BorshSerialize,
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        program::invoke_signed,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+fn create_pair_volume<'a>(
+    program_id: &Pubkey,
+    pair_volume_info: &AccountInfo<'a>,
+    payer_info: &AccountInfo<'a>,
+    source_wallet: &Pubkey,
+    destination_wallet: &Pubkey,
+    mint: &Pubkey,
+) -> ProgramResult {
+    let (address, bump) = PairVolume::address_with_bump_and_program_id(
+        source_wallet,
+        destination_wallet,
+        mint,
+        program_id,
+    );
+    if pair_volume_info.key != &address {
+        return Err(ProtocolError::IncorrectPairVolume.into());
+    }
+    if pair_volume_info.lamports() != 0 {
+        return Ok(());
+    }
+
+    let signer_seeds = &[
+        PairVolume::seed(),
+        source_wallet.as_ref(),
+        destination_wallet.as_ref(),
+        mint.as_ref(),
+        &[bump],
+    ];
+    invoke_signed(
+        &PairVolume::create_account_instruction(
+            payer_info.key,
+            source_wallet,
+            destination_wallet,
+            mint,
+            program_id,
+        ),
+        &[pair_volume_info.clone(), payer_info.clone()],
+        &[signer_seeds],
+    )?;
+    PairVolume::default().serialize(&mut &mut pair_volume_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+pub fn process_initialize_pair_volume(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Accounts expected by this instruction:
+    // 0. [w+s] Payer
+    // 1. []    Mint
+    // 2. []    First Wallet
+    // 3. []    Second Wallet
+    // 4. [w]   Pair Volume (First to Second)
+    // 5. [w]   Pair Volume (Second to First)
+    // 6. []    System Program
+    let payer_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let first_wallet_info = next_account_info(accounts_iter)?;
+    let second_wallet_info = next_account_info(accounts_iter)?;
+    let first_to_second_info = next_account_info(accounts_iter)?;
+    let second_to_first_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    create_pair_volume(
+        program_id,
+        first_to_second_info,
+        payer_info,
+        first_wallet_info.key,
+        second_wallet_info.key,
+        mint_info.key,
+    )?;
+    create_pair_volume(
+        program_id,
+        second_to_first_info,
+        payer_info,
+        second_wallet_info.key,
+        first_wallet_info.key,
+        mint_info.key,
+    )?;
+
+    ProtocolEvent::PairVolumeInitialized {
+        mint: *mint_info.key,
+        first_wallet: *first_wallet_info.key,
+        second_wallet: *second_wallet_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
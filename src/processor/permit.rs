@@ -0,0 +1,103 @@
+use {
+    crate::{
+        error::ProtocolError,
+        events::ProtocolEvent,
+        instruction::IssueTransferPermitInstruction,
+        state::{MintRules, TransferPermit},
+    },
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        program::invoke_signed,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+pub fn process_issue_transfer_permit(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: IssueTransferPermitInstruction,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Accounts expected by this instruction:
+    // 0. []    Mint
+    // 1. []    Mint Rules
+    // 2. [w+s] Permit Approver
+    // 3. []    Source Token Account
+    // 4. []    Destination Token Account
+    // 5. [w]   Transfer Permit
+    // 6. []    System Program
+    let mint_info = next_account_info(accounts_iter)?;
+    let mint_rules_info = next_account_info(accounts_iter)?;
+    let approver_info = next_account_info(accounts_iter)?;
+    let source_info = next_account_info(accounts_iter)?;
+    let destination_info = next_account_info(accounts_iter)?;
+    let permit_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    // Assert the permit is issued by the mint's permit approver.
+    if mint_rules_info.key != &MintRules::address_with_program_id(mint_info.key, program_id)
+        || mint_rules_info.owner != program_id
+    {
+        return Err(ProtocolError::IncorrectMintRules.into());
+    }
+    let rules = MintRules::try_from_slice(&mint_rules_info.data.borrow())?;
+    if approver_info.key != &rules.permit_approver {
+        return Err(ProtocolError::IncorrectPermitApprover.into());
+    }
+    if !approver_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Assert the correct permit account was provided.
+    let (permit_address, bump) = TransferPermit::address_with_bump_and_program_id(
+        source_info.key,
+        destination_info.key,
+        mint_info.key,
+        program_id,
+    );
+    if permit_info.key != &permit_address {
+        return Err(ProtocolError::IncorrectTransferPermit.into());
+    }
+
+    // A permit replaces any earlier permit between the same token accounts.
+    if permit_info.lamports() == 0 {
+        let signer_seeds = &[
+            TransferPermit::seed(),
+            source_info.key.as_ref(),
+            destination_info.key.as_ref(),
+            mint_info.key.as_ref(),
+            &[bump],
+        ];
+        invoke_signed(
+            &TransferPermit::create_account_instruction(
+                approver_info.key,
+                source_info.key,
+                destination_info.key,
+                mint_info.key,
+                program_id,
+            ),
+            &[permit_info.clone(), approver_info.clone()],
+            &[signer_seeds],
+        )?;
+    } else if permit_info.owner != program_id {
+        return Err(ProtocolError::IncorrectTransferPermit.into());
+    }
+
+    let permit = TransferPermit::new(approver_info.key, data.max_amount, data.expiry);
+    permit.serialize(&mut &mut permit_info.data.borrow_mut()[..])?;
+
+    ProtocolEvent::TransferPermitIssued {
+        mint: *mint_info.key,
+        source: *source_info.key,
+        destination: *destination_info.key,
+        max_amount: data.max_amount,
+        expiry: data.expiry,
+    }
+    .emit();
+
+    Ok(())
+}
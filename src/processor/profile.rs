@@ -1,6 +1,8 @@
 use {
     crate::{
         error::ProtocolError,
+        events::ProtocolEvent,
+        processor::link::{check_wallet_not_linked, write_wallet_link},
         state::{MintAuthority, Profile, Soulbound},
     },
     borsh::BorshSerialize,
@@ -14,7 +16,11 @@ use {
     spl_token_2022::{extension::StateWithExtensions, state::Account as TokenAccount},
 };
 
-pub fn process_initialize_profile(_program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+pub fn process_initialize_profile(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    namespace: &Pubkey,
+) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
     // Accounts expected by this instruction:
@@ -25,6 +31,7 @@ pub fn process_initialize_profile(_program_id: &Pubkey, accounts: &[AccountInfo]
     // 4. []    Souldbound Mint Authority
     // 5. []    Token-2022 Program
     // 6. []    System Program
+    // 7. [w]   User's Wallet Link
     let soulbound_mint_info = next_account_info(accounts_iter)?;
     let soulbound_token_account_info = next_account_info(accounts_iter)?;
     let profile_info = next_account_info(accounts_iter)?;
@@ -32,14 +39,17 @@ pub fn process_initialize_profile(_program_id: &Pubkey, accounts: &[AccountInfo]
     let soulbound_mint_authority_info = next_account_info(accounts_iter)?;
     let _token_2022_program_info = next_account_info(accounts_iter)?;
     let _system_program_info = next_account_info(accounts_iter)?;
+    let wallet_link_info = next_account_info(accounts_iter)?;
 
     // Assert the correct soulbound mint was provided.
-    if soulbound_mint_info.key != &Soulbound::address() {
+    if soulbound_mint_info.key != &Soulbound::address_with_program_id(namespace, program_id) {
         return Err(ProtocolError::IncorrectSoulboundMint.into());
     }
 
     // Assert the correct soulbound token account was provided.
-    if soulbound_token_account_info.key != &Soulbound::token_account(wallet_info.key) {
+    if soulbound_token_account_info.key
+        != &Soulbound::token_account_with_program_id(namespace, wallet_info.key, program_id)
+    {
         return Err(ProtocolError::IncorrectSoulboundTokenAccount.into());
     }
 
@@ -52,6 +62,9 @@ pub fn process_initialize_profile(_program_id: &Pubkey, accounts: &[AccountInfo]
         }
     }
 
+    // Assert the user's wallet is not already linked to another profile.
+    check_wallet_not_linked(wallet_link_info, wallet_info.key)?;
+
     // Assert the user's profile does not exist.
     if profile_info.lamports() != 0 {
         return Err(ProtocolError::ProfileAlreadyInitialized.into());
@@ -65,10 +78,10 @@ pub fn process_initialize_profile(_program_id: &Pubkey, accounts: &[AccountInfo]
     // Mint the user's soulbound token.
     {
         let seed = MintAuthority::seed();
-        let bump = MintAuthority::address_with_bump().1;
-        let signer_seeds = &[seed, &[bump]];
+        let bump = MintAuthority::address_with_bump_and_program_id(namespace, program_id).1;
+        let signer_seeds = &[seed, namespace.as_ref(), &[bump]];
         invoke_signed(
-            &Soulbound::mint_to_instruction(wallet_info.key),
+            &Soulbound::mint_to_instruction(namespace, wallet_info.key, program_id),
             &[
                 soulbound_mint_info.clone(),
                 soulbound_token_account_info.clone(),
@@ -81,10 +94,11 @@ pub fn process_initialize_profile(_program_id: &Pubkey, accounts: &[AccountInfo]
     // Initialize the user's profile.
     {
         let seed = Profile::seed();
-        let bump = Profile::address_with_bump(wallet_info.key).1;
-        let signer_seeds = &[seed, wallet_info.key.as_ref(), &[bump]];
+        let bump =
+            Profile::address_with_bump_and_program_id(namespace, wallet_info.key, program_id).1;
+        let signer_seeds = &[seed, namespace.as_ref(), wallet_info.key.as_ref(), &[bump]];
         invoke_signed(
-            &Profile::create_account_instruction(wallet_info.key),
+            &Profile::create_account_instruction(namespace, wallet_info.key, program_id),
             &[profile_info.clone(), wallet_info.clone()],
             &[signer_seeds],
         )?;
@@ -93,5 +107,21 @@ pub fn process_initialize_profile(_program_id: &Pubkey, accounts: &[AccountInfo]
     let profile = Profile::new(wallet_info.key);
     profile.serialize(&mut &mut profile_info.data.borrow_mut()[..])?;
 
+    // Link the user's wallet to its own profile.
+    write_wallet_link(
+        program_id,
+        wallet_link_info,
+        wallet_info,
+        namespace,
+        wallet_info.key,
+        wallet_info.key,
+    )?;
+
+    ProtocolEvent::ProfileInitialized {
+        namespace: *namespace,
+        wallet: *wallet_info.key,
+    }
+    .emit();
+
     Ok(())
 }
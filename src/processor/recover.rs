@@ -0,0 +1,232 @@
+use {
+    crate::{
+        error::ProtocolError,
+        events::ProtocolEvent,
+        processor::{
+            admin::check_admin,
+            link::{check_wallet_not_linked, write_wallet_link},
+            utils::close_account,
+        },
+        state::{MintAuthority, Profile, Soulbound, WalletLink},
+    },
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        program::invoke_signed,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+    spl_token_2022::{extension::StateWithExtensions, state::Account as TokenAccount},
+};
+
+fn get_soulbound_balance(token_account_info: &AccountInfo) -> Result<u64, ProgramError> {
+    let token_account_data = token_account_info.data.borrow();
+    let token_account = StateWithExtensions::<TokenAccount>::unpack(&token_account_data)?;
+    Ok(token_account.base.amount)
+}
+
+pub fn process_recover_profile(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Accounts expected by this instruction:
+    // 0. []    Protocol Config
+    // 1. [w+s] Protocol Admin
+    // 2. [w]   Soulbound Mint
+    // 3. []    Soulbound Mint Authority
+    // 4. [w]   Old Wallet's Soulbound Token Account
+    // 5. [w]   Old Profile
+    // 6. [w]   Old Wallet Link
+    // 7. []    Old Wallet
+    // 8. [w]   New Wallet's Soulbound Token Account
+    // 9. [w]   New Profile
+    // 10. [w]  New Wallet Link
+    // 11. [s]  New Wallet
+    // 12. []   Token-2022 Program
+    // 13. []   System Program
+    //
+    // Followed by a `[]` Linked Wallet and `[w]` Linked Wallet's Link pair
+    // for each secondary wallet linked to the profile.
+    let config_info = next_account_info(accounts_iter)?;
+    let admin_info = next_account_info(accounts_iter)?;
+    let soulbound_mint_info = next_account_info(accounts_iter)?;
+    let soulbound_mint_authority_info = next_account_info(accounts_iter)?;
+    let old_soulbound_token_account_info = next_account_info(accounts_iter)?;
+    let old_profile_info = next_account_info(accounts_iter)?;
+    let old_link_info = next_account_info(accounts_iter)?;
+    let old_wallet_info = next_account_info(accounts_iter)?;
+    let new_soulbound_token_account_info = next_account_info(accounts_iter)?;
+    let new_profile_info = next_account_info(accounts_iter)?;
+    let new_link_info = next_account_info(accounts_iter)?;
+    let new_wallet_info = next_account_info(accounts_iter)?;
+    let _token_2022_program_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    // Assert the recovery is approved by the protocol admin.
+    let config = check_admin(program_id, config_info, admin_info)?;
+    let namespace = &config.namespace;
+
+    // Assert the new wallet is the signer.
+    if !new_wallet_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if old_wallet_info.key == new_wallet_info.key {
+        return Err(ProtocolError::RecoveryWalletUnchanged.into());
+    }
+
+    // Assert the new wallet has neither a profile nor a wallet link.
+    if new_profile_info.lamports() != 0 {
+        return Err(ProtocolError::ProfileAlreadyInitialized.into());
+    }
+    check_wallet_not_linked(new_link_info, new_wallet_info.key)?;
+    if old_link_info.key
+        != &WalletLink::address_with_program_id(namespace, old_wallet_info.key, program_id)
+    {
+        return Err(ProtocolError::IncorrectWalletLink.into());
+    }
+
+    // Assert the correct soulbound mint was provided.
+    if soulbound_mint_info.key != &Soulbound::address_with_program_id(namespace, program_id) {
+        return Err(ProtocolError::IncorrectSoulboundMint.into());
+    }
+
+    // Assert the correct soulbound token accounts were provided, and that the
+    // new wallet's soulbound token account does not have any tokens.
+    if old_soulbound_token_account_info.key
+        != &Soulbound::token_account_with_program_id(namespace, old_wallet_info.key, program_id)
+        || new_soulbound_token_account_info.key
+            != &Soulbound::token_account_with_program_id(namespace, new_wallet_info.key, program_id)
+    {
+        return Err(ProtocolError::IncorrectSoulboundTokenAccount.into());
+    }
+    if get_soulbound_balance(new_soulbound_token_account_info)? != 0 {
+        return Err(ProtocolError::SoulboundTokenAccountHasTokens.into());
+    }
+
+    // Assert the old wallet's profile exists.
+    if old_profile_info.key
+        != &Profile::address_with_program_id(namespace, old_wallet_info.key, program_id)
+        || old_profile_info.lamports() == 0
+        || old_profile_info.owner != program_id
+    {
+        return Err(ProtocolError::ProfileNotInitialized.into());
+    }
+
+    let seed = MintAuthority::seed();
+    let bump = MintAuthority::address_with_bump_and_program_id(namespace, program_id).1;
+    let mint_authority_signer_seeds = &[seed, namespace.as_ref(), &[bump]];
+
+    // Burn the old wallet's soulbound token, as the soulbound mint's
+    // permanent delegate.
+    let old_balance = get_soulbound_balance(old_soulbound_token_account_info)?;
+    if old_balance > 0 {
+        invoke_signed(
+            &Soulbound::burn_instruction(namespace, old_wallet_info.key, old_balance, program_id),
+            &[
+                old_soulbound_token_account_info.clone(),
+                soulbound_mint_info.clone(),
+                soulbound_mint_authority_info.clone(),
+            ],
+            &[mint_authority_signer_seeds],
+        )?;
+    }
+
+    // Mint the new wallet's soulbound token.
+    invoke_signed(
+        &Soulbound::mint_to_instruction(namespace, new_wallet_info.key, program_id),
+        &[
+            soulbound_mint_info.clone(),
+            new_soulbound_token_account_info.clone(),
+            soulbound_mint_authority_info.clone(),
+        ],
+        &[mint_authority_signer_seeds],
+    )?;
+
+    // Move the profile to the new wallet.
+    let mut profile = Profile::try_from_slice(&old_profile_info.data.borrow())?;
+    profile.wallet_address = *new_wallet_info.key;
+    {
+        let seed = Profile::seed();
+        let bump =
+            Profile::address_with_bump_and_program_id(namespace, new_wallet_info.key, program_id).1;
+        let signer_seeds = &[
+            seed,
+            namespace.as_ref(),
+            new_wallet_info.key.as_ref(),
+            &[bump],
+        ];
+        invoke_signed(
+            &Profile::create_account_instruction_with_payer(
+                admin_info.key,
+                namespace,
+                new_wallet_info.key,
+                program_id,
+            ),
+            &[new_profile_info.clone(), admin_info.clone()],
+            &[signer_seeds],
+        )?;
+    }
+    profile.serialize(&mut &mut new_profile_info.data.borrow_mut()[..])?;
+
+    // Link the new wallet to its own profile.
+    write_wallet_link(
+        program_id,
+        new_link_info,
+        admin_info,
+        namespace,
+        new_wallet_info.key,
+        new_wallet_info.key,
+    )?;
+
+    // Re-point every secondary wallet linked to the profile at the new
+    // wallet. A link passed twice no longer points at the old wallet the
+    // second time, so it can't stand in for another link.
+    let mut relinked = 0;
+    while let Ok(linked_wallet_info) = next_account_info(accounts_iter) {
+        let linked_link_info = next_account_info(accounts_iter)?;
+        if linked_wallet_info.key == old_wallet_info.key
+            || linked_link_info.key
+                != &WalletLink::address_with_program_id(
+                    namespace,
+                    linked_wallet_info.key,
+                    program_id,
+                )
+            || linked_link_info.owner != program_id
+        {
+            return Err(ProtocolError::IncorrectWalletLink.into());
+        }
+        let link = WalletLink::try_from_slice(&linked_link_info.data.borrow())?;
+        if &link.primary_wallet != old_wallet_info.key {
+            return Err(ProtocolError::IncorrectWalletLink.into());
+        }
+        write_wallet_link(
+            program_id,
+            linked_link_info,
+            admin_info,
+            namespace,
+            linked_wallet_info.key,
+            new_wallet_info.key,
+        )?;
+        relinked += 1;
+    }
+    if relinked != profile.linked_wallets {
+        return Err(ProtocolError::LinkedWalletsMissing.into());
+    }
+
+    // Close the old profile and the old wallet's link, so the lost wallet can
+    // no longer trade under the profile.
+    close_account(old_profile_info, admin_info)?;
+    if old_link_info.lamports() != 0 {
+        close_account(old_link_info, admin_info)?;
+    }
+
+    ProtocolEvent::ProfileRecovered {
+        namespace: *namespace,
+        old_wallet: *old_wallet_info.key,
+        new_wallet: *new_wallet_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
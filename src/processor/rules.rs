@@ -0,0 +1,51 @@
+use {
+    crate::{
+        error::ProtocolError,
+        events::ProtocolEvent,
+        processor::utils::{check_credential_mint, check_mint_authority, get_mint_rules},
+        state::MintRules,
+    },
+    borsh::BorshSerialize,
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        pubkey::Pubkey,
+    },
+};
+
+pub fn process_update_mint_rules(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    rules: MintRules,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Accounts expected by this instruction:
+    // 0. []    Mint
+    // 1. [s]   Mint Authority
+    // 2. [w]   Mint Rules
+    // 3. []    Credential Mint
+    let mint_info = next_account_info(accounts_iter)?;
+    let mint_authority_info = next_account_info(accounts_iter)?;
+    let mint_rules_info = next_account_info(accounts_iter)?;
+    let credential_mint_info = next_account_info(accounts_iter)?;
+
+    check_mint_authority(mint_info, mint_authority_info)?;
+
+    // Assert the mint stays in its namespace.
+    let current_rules = get_mint_rules(program_id, mint_info, mint_rules_info)?;
+    if rules.namespace != current_rules.namespace {
+        return Err(ProtocolError::IncorrectNamespace.into());
+    }
+
+    check_credential_mint(credential_mint_info, &rules)?;
+
+    rules.serialize(&mut &mut mint_rules_info.data.borrow_mut()[..])?;
+
+    ProtocolEvent::MintRulesUpdated {
+        mint: *mint_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use {
+    crate::{
+        error::ProtocolError,
+        events::ProtocolEvent,
+        processor::{
+            admin::check_admin,
+            utils::{check_mint_authority, get_mint_rules, resize_account},
+        },
+        state::{TradingOverride, TradingSchedule, TradingWindow},
+    },
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        program::invoke_signed,
+        pubkey::Pubkey,
+    },
+};
+
+/// Applies an update to a mint's trading schedule, creating the schedule
+/// account if it does not exist yet.
+fn update_trading_schedule<'a>(
+    program_id: &Pubkey,
+    mint_info: &AccountInfo<'a>,
+    payer_info: &AccountInfo<'a>,
+    schedule_info: &AccountInfo<'a>,
+    update: impl FnOnce(&mut TradingSchedule),
+) -> ProgramResult {
+    let (schedule_address, bump) =
+        TradingSchedule::address_with_bump_and_program_id(mint_info.key, program_id);
+    if schedule_info.key != &schedule_address {
+        return Err(ProtocolError::IncorrectTradingSchedule.into());
+    }
+
+    let mut schedule = if schedule_info.lamports() == 0 {
+        let signer_seeds = &[TradingSchedule::seed(), mint_info.key.as_ref(), &[bump]];
+        invoke_signed(
+            &TradingSchedule::create_account_instruction(payer_info.key, mint_info.key, program_id),
+            &[schedule_info.clone(), payer_info.clone()],
+            &[signer_seeds],
+        )?;
+        TradingSchedule::default()
+    } else if schedule_info.owner != program_id {
+        return Err(ProtocolError::IncorrectTradingSchedule.into());
+    } else {
+        TradingSchedule::try_from_slice(&schedule_info.data.borrow())?
+    };
+    update(&mut schedule);
+
+    resize_account(schedule_info, payer_info, schedule.packed_len())?;
+    schedule.serialize(&mut &mut schedule_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+pub fn process_set_trading_windows(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    windows: Vec<TradingWindow>,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Accounts expected by this instruction:
+    // 0. []    Mint
+    // 1. [w+s] Mint Authority
+    // 2. [w]   Trading Schedule
+    // 3. []    System Program
+    let mint_info = next_account_info(accounts_iter)?;
+    let mint_authority_info = next_account_info(accounts_iter)?;
+    let schedule_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    check_mint_authority(mint_info, mint_authority_info)?;
+
+    update_trading_schedule(
+        program_id,
+        mint_info,
+        mint_authority_info,
+        schedule_info,
+        |schedule| schedule.windows = windows,
+    )?;
+
+    ProtocolEvent::TradingWindowsSet {
+        mint: *mint_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
+
+pub fn process_set_trading_override(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    trading_override: TradingOverride,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Accounts expected by this instruction:
+    // 0. []    Protocol Config
+    // 1. [w+s] Protocol Admin
+    // 2. []    Mint
+    // 3. [w]   Trading Schedule
+    // 4. []    System Program
+    // 5. []    Mint Rules
+    let config_info = next_account_info(accounts_iter)?;
+    let admin_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let schedule_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+    let mint_rules_info = next_account_info(accounts_iter)?;
+
+    // Assert the admin is the admin of the mint's namespace.
+    let config = check_admin(program_id, config_info, admin_info)?;
+    let rules = get_mint_rules(program_id, mint_info, mint_rules_info)?;
+    if rules.namespace != config.namespace {
+        return Err(ProtocolError::IncorrectNamespace.into());
+    }
+
+    update_trading_schedule(
+        program_id,
+        mint_info,
+        admin_info,
+        schedule_info,
+        |schedule| schedule.trading_override = trading_override.clone(),
+    )?;
+
+    ProtocolEvent::TradingOverrideSet {
+        mint: *mint_info.key,
+        trading_override,
+    }
+    .emit();
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use {
+    crate::{error::ProtocolError, state::MintRules},
+    borsh::BorshDeserialize,
+    solana_program::{
+        account_info::AccountInfo, entrypoint::ProgramResult, program::invoke,
+        program_error::ProgramError, program_option::COption, pubkey::Pubkey, rent::Rent,
+        system_instruction, system_program, sysvar::Sysvar,
+    },
+    spl_token_2022::{
+        extension::{
+            non_transferable::NonTransferable, BaseStateWithExtensions, StateWithExtensions,
+        },
+        state::Mint,
+    },
+};
+
+/// Resizes a program-owned account, topping up rent from the payer when it
+/// grows and refunding excess rent to the payer when it shrinks.
+pub(crate) fn resize_account<'a>(
+    account_info: &AccountInfo<'a>,
+    payer_info: &AccountInfo<'a>,
+    new_len: usize,
+) -> ProgramResult {
+    let required_lamports = Rent::get()?.minimum_balance(new_len);
+    let current_lamports = account_info.lamports();
+
+    if required_lamports > current_lamports {
+        invoke(
+            &system_instruction::transfer(
+                payer_info.key,
+                account_info.key,
+                required_lamports - current_lamports,
+            ),
+            &[payer_info.clone(), account_info.clone()],
+        )?;
+    } else if current_lamports > required_lamports {
+        let excess = current_lamports - required_lamports;
+        **account_info.try_borrow_mut_lamports()? -= excess;
+        **payer_info.try_borrow_mut_lamports()? += excess;
+    }
+
+    account_info.realloc(new_len, false)
+}
+
+/// Closes a program-owned account, returning its lamports to the destination.
+pub(crate) fn close_account(
+    account_info: &AccountInfo,
+    destination_info: &AccountInfo,
+) -> ProgramResult {
+    let lamports = account_info.lamports();
+    **account_info.try_borrow_mut_lamports()? = 0;
+    **destination_info.try_borrow_mut_lamports()? += lamports;
+
+    account_info.realloc(0, false)?;
+    account_info.assign(&system_program::id());
+
+    Ok(())
+}
+
+/// Asserts the mint authority account is the mint's authority, signing the
+/// transaction.
+pub(crate) fn check_mint_authority(
+    mint_info: &AccountInfo,
+    mint_authority_info: &AccountInfo,
+) -> ProgramResult {
+    if mint_info.owner != &spl_token_2022::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    if mint.base.mint_authority != COption::Some(*mint_authority_info.key) {
+        return Err(ProtocolError::IncorrectMintAuthority.into());
+    }
+    if !mint_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(())
+}
+
+/// Reads a mint's transfer rules, asserting the correct rules account was
+/// provided.
+pub(crate) fn get_mint_rules(
+    program_id: &Pubkey,
+    mint_info: &AccountInfo,
+    mint_rules_info: &AccountInfo,
+) -> Result<MintRules, ProgramError> {
+    if mint_rules_info.key != &MintRules::address_with_program_id(mint_info.key, program_id)
+        || mint_rules_info.owner != program_id
+    {
+        return Err(ProtocolError::IncorrectMintRules.into());
+    }
+    MintRules::try_from_slice(&mint_rules_info.data.borrow()).map_err(Into::into)
+}
+
+/// Asserts the credential mint account is the mint's credential mint, and is
+/// a non-transferable Token-2022 mint.
+pub(crate) fn check_credential_mint(
+    credential_mint_info: &AccountInfo,
+    rules: &MintRules,
+) -> ProgramResult {
+    if credential_mint_info.key != &rules.credential_mint
+        || credential_mint_info.owner != &spl_token_2022::id()
+    {
+        return Err(ProtocolError::IncorrectCredentialMint.into());
+    }
+    let mint_data = credential_mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    if mint.get_extension::<NonTransferable>().is_err() {
+        return Err(ProtocolError::IncorrectCredentialMint.into());
+    }
+    Ok(())
+}
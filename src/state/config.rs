@@ -0,0 +1,63 @@
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{instruction::Instruction, pubkey::Pubkey, sysvar::Sysvar},
+};
+
+/// The protocol's configuration.
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct ProtocolConfig {
+    /// The protocol admin.
+    pub admin: Pubkey,
+    /// The namespace the config belongs to.
+    pub namespace: Pubkey,
+}
+
+impl ProtocolConfig {
+    pub const LEN: usize = 32 + 32;
+
+    pub fn seed<'s>() -> &'s [u8] {
+        b"config"
+    }
+
+    pub fn address_with_bump(namespace: &Pubkey) -> (Pubkey, u8) {
+        Self::address_with_bump_and_program_id(namespace, &crate::id())
+    }
+
+    pub fn address_with_bump_and_program_id(
+        namespace: &Pubkey,
+        program_id: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::seed(), namespace.as_ref()], program_id)
+    }
+
+    pub fn address(namespace: &Pubkey) -> Pubkey {
+        Self::address_with_program_id(namespace, &crate::id())
+    }
+
+    pub fn address_with_program_id(namespace: &Pubkey, program_id: &Pubkey) -> Pubkey {
+        Self::address_with_bump_and_program_id(namespace, program_id).0
+    }
+
+    pub fn new(admin: &Pubkey, namespace: &Pubkey) -> Self {
+        let admin = *admin;
+        let namespace = *namespace;
+        Self { admin, namespace }
+    }
+
+    pub fn create_account_instruction(
+        payer_address: &Pubkey,
+        namespace: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
+        let lamports = solana_program::rent::Rent::get()
+            .unwrap()
+            .minimum_balance(Self::LEN);
+        solana_program::system_instruction::create_account(
+            payer_address,
+            &Self::address_with_program_id(namespace, program_id),
+            lamports,
+            Self::LEN as u64,
+            program_id,
+        )
+    }
+}
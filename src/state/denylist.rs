@@ -0,0 +1,67 @@
+use {
+    super::pubkey_set::PubkeySet,
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{
+        instruction::Instruction, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    },
+};
+
+/// Wallets barred from sending or receiving protocol tokens, managed by the
+/// protocol admin.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Default)]
+pub struct Denylist {
+    pub wallets: PubkeySet,
+}
+
+impl Denylist {
+    pub fn seed<'s>() -> &'s [u8] {
+        b"denylist"
+    }
+
+    pub fn address_with_bump(namespace: &Pubkey) -> (Pubkey, u8) {
+        Self::address_with_bump_and_program_id(namespace, &crate::id())
+    }
+
+    pub fn address_with_bump_and_program_id(
+        namespace: &Pubkey,
+        program_id: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::seed(), namespace.as_ref()], program_id)
+    }
+
+    pub fn address(namespace: &Pubkey) -> Pubkey {
+        Self::address_with_program_id(namespace, &crate::id())
+    }
+
+    pub fn address_with_program_id(namespace: &Pubkey, program_id: &Pubkey) -> Pubkey {
+        Self::address_with_bump_and_program_id(namespace, program_id).0
+    }
+
+    pub fn packed_len(&self) -> usize {
+        PubkeySet::packed_len(self.wallets.len())
+    }
+
+    /// Returns whether a packed denylist holds the wallet, without
+    /// deserializing it.
+    pub fn packed_contains(data: &[u8], wallet_address: &Pubkey) -> Result<bool, ProgramError> {
+        PubkeySet::packed_contains(data, wallet_address)
+    }
+
+    pub fn create_account_instruction(
+        payer_address: &Pubkey,
+        namespace: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
+        let space = Self::default().packed_len();
+        let lamports = solana_program::rent::Rent::get()
+            .unwrap()
+            .minimum_balance(space);
+        solana_program::system_instruction::create_account(
+            payer_address,
+            &Self::address_with_program_id(namespace, program_id),
+            lamports,
+            space as u64,
+            program_id,
+        )
+    }
+}
@@ -0,0 +1,85 @@
+use {
+    super::pubkey_set::PubkeySet,
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{
+        instruction::Instruction, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    },
+};
+
+/// Token account owners exempt from a protocol mint's requirements, managed by
+/// the mint authority.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Default)]
+pub struct Exemptions {
+    /// Owners exempt from the soulbound and profile requirements, such as
+    /// pool, vault or escrow authorities.
+    pub owners: PubkeySet,
+    /// Owners exempt from the mint's maximum balance, such as treasuries.
+    pub balance_cap_owners: PubkeySet,
+}
+
+impl Exemptions {
+    pub fn seed<'s>() -> &'s [u8] {
+        b"exemptions"
+    }
+
+    pub fn address_with_bump(mint: &Pubkey) -> (Pubkey, u8) {
+        Self::address_with_bump_and_program_id(mint, &crate::id())
+    }
+
+    pub fn address_with_bump_and_program_id(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::seed(), mint.as_ref()], program_id)
+    }
+
+    pub fn address(mint: &Pubkey) -> Pubkey {
+        Self::address_with_program_id(mint, &crate::id())
+    }
+
+    pub fn address_with_program_id(mint: &Pubkey, program_id: &Pubkey) -> Pubkey {
+        Self::address_with_bump_and_program_id(mint, program_id).0
+    }
+
+    pub fn packed_len(&self) -> usize {
+        PubkeySet::packed_len(self.owners.len())
+            + PubkeySet::packed_len(self.balance_cap_owners.len())
+    }
+
+    /// Returns whether packed exemptions exempt the owner from the soulbound
+    /// and profile requirements, without deserializing them.
+    pub fn packed_owners_contains(data: &[u8], owner: &Pubkey) -> Result<bool, ProgramError> {
+        PubkeySet::packed_contains(data, owner)
+    }
+
+    /// Returns whether packed exemptions exempt the owner from the mint's
+    /// maximum balance, without deserializing them.
+    pub fn packed_balance_cap_owners_contains(
+        data: &[u8],
+        owner: &Pubkey,
+    ) -> Result<bool, ProgramError> {
+        let owners_len = data
+            .get(..4)
+            .map(|len| u32::from_le_bytes(len.try_into().unwrap()) as usize)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let balance_cap_owners = data
+            .get(PubkeySet::packed_len(owners_len)..)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        PubkeySet::packed_contains(balance_cap_owners, owner)
+    }
+
+    pub fn create_account_instruction(
+        payer_address: &Pubkey,
+        mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
+        let space = Self::default().packed_len();
+        let lamports = solana_program::rent::Rent::get()
+            .unwrap()
+            .minimum_balance(space);
+        solana_program::system_instruction::create_account(
+            payer_address,
+            &Self::address_with_program_id(mint, program_id),
+            lamports,
+            space as u64,
+            program_id,
+        )
+    }
+}
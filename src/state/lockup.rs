@@ -0,0 +1,84 @@
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{instruction::Instruction, pubkey::Pubkey, sysvar::Sysvar},
+};
+
+/// Tokens of a protocol mint locked in a wallet, set by the mint authority.
+///
+/// Nothing unlocks before `unlock_start`, the cliff. From then, the locked
+/// amount unlocks linearly until `unlock_end`, when it is fully unlocked.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct Lockup {
+    /// The amount locked before the cliff.
+    pub locked_amount: u64,
+    /// The Unix timestamp of the cliff.
+    pub unlock_start: i64,
+    /// The Unix timestamp at which the full amount is unlocked.
+    pub unlock_end: i64,
+}
+
+impl Lockup {
+    pub const LEN: usize = 8 + 8 + 8;
+
+    pub fn seed<'s>() -> &'s [u8] {
+        b"lockup"
+    }
+
+    pub fn address_with_bump(wallet_address: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+        Self::address_with_bump_and_program_id(wallet_address, mint, &crate::id())
+    }
+
+    pub fn address_with_bump_and_program_id(
+        wallet_address: &Pubkey,
+        mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::seed(), wallet_address.as_ref(), mint.as_ref()],
+            program_id,
+        )
+    }
+
+    pub fn address(wallet_address: &Pubkey, mint: &Pubkey) -> Pubkey {
+        Self::address_with_program_id(wallet_address, mint, &crate::id())
+    }
+
+    pub fn address_with_program_id(
+        wallet_address: &Pubkey,
+        mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Pubkey {
+        Self::address_with_bump_and_program_id(wallet_address, mint, program_id).0
+    }
+
+    /// Returns the amount still locked at the given time.
+    pub fn locked_at(&self, unix_timestamp: i64) -> u64 {
+        if unix_timestamp < self.unlock_start {
+            return self.locked_amount;
+        }
+        if unix_timestamp >= self.unlock_end {
+            return 0;
+        }
+        let remaining = (self.unlock_end - unix_timestamp) as u128;
+        let duration = (self.unlock_end - self.unlock_start) as u128;
+        (self.locked_amount as u128 * remaining / duration) as u64
+    }
+
+    pub fn create_account_instruction(
+        payer_address: &Pubkey,
+        wallet_address: &Pubkey,
+        mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
+        let lamports = solana_program::rent::Rent::get()
+            .unwrap()
+            .minimum_balance(Self::LEN);
+        solana_program::system_instruction::create_account(
+            payer_address,
+            &Self::address_with_program_id(wallet_address, mint, program_id),
+            lamports,
+            Self::LEN as u64,
+            program_id,
+        )
+    }
+}
@@ -0,0 +1,93 @@
+use {
+    super::RollingVolume,
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{instruction::Instruction, pubkey::Pubkey, sysvar::Sysvar},
+};
+
+/// A profile's outgoing transfers of a single protocol mint, from its primary
+/// wallet and every wallet linked to it, used to enforce the mint's transfer
+/// cooldown and the profile's outflow limit for the mint. Keyed by the
+/// profile's primary wallet.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Default, PartialEq)]
+pub struct MintActivity {
+    /// The slot of the profile's last outgoing transfer of the mint, or zero
+    /// if none.
+    pub last_transfer_slot: u64,
+    /// The maximum amount of the mint the profile may send within the outflow
+    /// window, set by the protocol admin, or zero for no limit.
+    pub outflow_limit: u64,
+    /// The amount of the mint the profile sent within the outflow window.
+    pub outflow: RollingVolume,
+}
+
+impl MintActivity {
+    pub const LEN: usize = 8 + 8 + RollingVolume::LEN;
+
+    /// The length of the sliding outflow window, in seconds.
+    pub const OUTFLOW_WINDOW: i64 = 24 * 60 * 60;
+
+    pub fn seed<'s>() -> &'s [u8] {
+        b"mint_activity"
+    }
+
+    pub fn address_with_bump(wallet_address: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+        Self::address_with_bump_and_program_id(wallet_address, mint, &crate::id())
+    }
+
+    pub fn address_with_bump_and_program_id(
+        wallet_address: &Pubkey,
+        mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::seed(), wallet_address.as_ref(), mint.as_ref()],
+            program_id,
+        )
+    }
+
+    pub fn address(wallet_address: &Pubkey, mint: &Pubkey) -> Pubkey {
+        Self::address_with_program_id(wallet_address, mint, &crate::id())
+    }
+
+    pub fn address_with_program_id(
+        wallet_address: &Pubkey,
+        mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Pubkey {
+        Self::address_with_bump_and_program_id(wallet_address, mint, program_id).0
+    }
+
+    /// Records an outgoing transfer at the given time. Returns `false`, without
+    /// recording it, if the transfer would exceed the outflow limit within the
+    /// window ending at that time.
+    pub fn record_outflow(&mut self, amount: u64, unix_timestamp: i64) -> bool {
+        let outflow = self
+            .outflow
+            .volume_at(unix_timestamp, Self::OUTFLOW_WINDOW)
+            .saturating_add(amount);
+        if self.outflow_limit != 0 && outflow > self.outflow_limit {
+            return false;
+        }
+        self.outflow
+            .record(amount, unix_timestamp, Self::OUTFLOW_WINDOW);
+        true
+    }
+
+    pub fn create_account_instruction(
+        payer_address: &Pubkey,
+        wallet_address: &Pubkey,
+        mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
+        let lamports = solana_program::rent::Rent::get()
+            .unwrap()
+            .minimum_balance(Self::LEN);
+        solana_program::system_instruction::create_account(
+            payer_address,
+            &Self::address_with_program_id(wallet_address, mint, program_id),
+            lamports,
+            Self::LEN as u64,
+            program_id,
+        )
+    }
+}
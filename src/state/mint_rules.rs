@@ -0,0 +1,134 @@
+use {
+    super::{Soulbound, DEFAULT_NAMESPACE},
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{instruction::Instruction, pubkey::Pubkey, sysvar::Sysvar},
+};
+
+/// The transfer rules for a protocol mint, set by the mint authority.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct MintRules {
+    /// The non-transferable Token-2022 mint whose tokens serve as the
+    /// credential for the mint, such as a community's membership token.
+    /// Defaults to the namespace's soulbound mint.
+    pub credential_mint: Pubkey,
+    /// The namespace whose profiles, denylist and order tracker the mint's
+    /// transfers use. Set when the mint is created and cannot be changed.
+    pub namespace: Pubkey,
+    /// Whether the source owner must hold a soulbound token and a profile.
+    pub require_source_credential: bool,
+    /// Whether the destination owner must hold a soulbound token and a
+    /// profile.
+    pub require_destination_credential: bool,
+    /// Whether a delegate transferring on the source owner's behalf must hold
+    /// a soulbound token and a profile.
+    pub require_delegate_credential: bool,
+    /// Whether transfers are recorded in profile and order tracker volume.
+    pub track_volume: bool,
+    /// The minimum amount of a single transfer, or zero for no minimum.
+    pub min_amount: u64,
+    /// The maximum amount of a single transfer, or zero for no maximum.
+    pub max_amount: u64,
+    /// The maximum balance a non-exempt token account may hold after a
+    /// transfer, or zero for no maximum.
+    pub max_balance: u64,
+    /// The minimum number of slots between outgoing transfers of the mint from
+    /// the same wallet, or zero for no cooldown. Wallets must initialize their
+    /// mint activity account to send the mint while a cooldown is set.
+    pub transfer_cooldown_slots: u64,
+    /// The share, in basis points, of the volume sent from one profile to
+    /// another that, once sent back within the wash trading window, flags
+    /// both profiles. Zero disables wash trading detection. While enabled,
+    /// transfers between two profiles require the pair volume accounts between
+    /// their primary wallets.
+    pub wash_trade_ratio_bps: u16,
+    /// The length of the wash trading window, in seconds.
+    pub wash_trade_window: i64,
+    /// Whether transfers to or from flagged profiles are rejected.
+    pub block_wash_trading: bool,
+    /// The amount above which a transfer requires a permit from the permit
+    /// approver, or zero for no permits.
+    pub permit_threshold: u64,
+    /// The account that issues transfer permits, such as a compliance officer.
+    pub permit_approver: Pubkey,
+    /// The amount above which a transfer must include an SPL Memo instruction
+    /// in the same transaction, or zero for no memos.
+    pub memo_threshold: u64,
+}
+
+impl Default for MintRules {
+    fn default() -> Self {
+        Self::new(&DEFAULT_NAMESPACE)
+    }
+}
+
+impl MintRules {
+    pub const LEN: usize = 32 + 32 + 1 + 1 + 1 + 1 + 8 + 8 + 8 + 8 + 2 + 8 + 1 + 8 + 32 + 8;
+
+    /// The offset of the credential mint in the account's data, from which
+    /// the transfer hook derives credential token accounts. New fields must
+    /// be added after the namespace.
+    pub const CREDENTIAL_MINT_OFFSET: u8 = 0;
+
+    /// The offset of the namespace in the account's data, from which the
+    /// transfer hook derives the namespace's accounts.
+    pub const NAMESPACE_OFFSET: u8 = 32;
+
+    /// The default rules for a mint in the given namespace.
+    pub fn new(namespace: &Pubkey) -> Self {
+        Self {
+            credential_mint: Soulbound::address(namespace),
+            namespace: *namespace,
+            require_source_credential: true,
+            require_destination_credential: true,
+            require_delegate_credential: true,
+            track_volume: true,
+            min_amount: 0,
+            max_amount: 0,
+            max_balance: 0,
+            transfer_cooldown_slots: 0,
+            wash_trade_ratio_bps: 0,
+            wash_trade_window: 0,
+            block_wash_trading: false,
+            permit_threshold: 0,
+            permit_approver: Pubkey::default(),
+            memo_threshold: 0,
+        }
+    }
+
+    pub fn seed<'s>() -> &'s [u8] {
+        b"mint_rules"
+    }
+
+    pub fn address_with_bump(mint: &Pubkey) -> (Pubkey, u8) {
+        Self::address_with_bump_and_program_id(mint, &crate::id())
+    }
+
+    pub fn address_with_bump_and_program_id(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::seed(), mint.as_ref()], program_id)
+    }
+
+    pub fn address(mint: &Pubkey) -> Pubkey {
+        Self::address_with_program_id(mint, &crate::id())
+    }
+
+    pub fn address_with_program_id(mint: &Pubkey, program_id: &Pubkey) -> Pubkey {
+        Self::address_with_bump_and_program_id(mint, program_id).0
+    }
+
+    pub fn create_account_instruction(
+        payer_address: &Pubkey,
+        mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
+        let lamports = solana_program::rent::Rent::get()
+            .unwrap()
+            .minimum_balance(Self::LEN);
+        solana_program::system_instruction::create_account(
+            payer_address,
+            &Self::address_with_program_id(mint, program_id),
+            lamports,
+            Self::LEN as u64,
+            program_id,
+        )
+    }
+}
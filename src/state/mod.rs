@@ -1,10 +1,55 @@
+pub mod config;
+pub mod denylist;
+pub mod exemptions;
+pub mod lockup;
+pub mod mint_activity;
+pub mod mint_rules;
 pub mod order_tracker;
+pub mod pair_volume;
 pub mod profile;
+pub mod program_filter;
+pub mod pubkey_set;
+pub mod rolling_volume;
 pub mod soulbound;
+pub mod trading_schedule;
+pub mod transfer_history;
+pub mod transfer_permit;
 pub mod validation;
+pub mod wallet_link;
+
+/// The namespace of the protocol deployed with the program. Each namespace is
+/// an independent protocol with its own admin, soulbound mint, profiles and
+/// order tracker.
+pub const DEFAULT_NAMESPACE: solana_program::pubkey::Pubkey =
+    solana_program::pubkey::Pubkey::new_from_array([0; 32]);
+
+/// Derives the address of the program's data account, which names the
+/// program's upgrade authority.
+pub fn get_program_data_address(
+    program_id: &solana_program::pubkey::Pubkey,
+) -> solana_program::pubkey::Pubkey {
+    solana_program::pubkey::Pubkey::find_program_address(
+        &[program_id.as_ref()],
+        &solana_program::bpf_loader_upgradeable::id(),
+    )
+    .0
+}
 
 pub use {
-    order_tracker::OrderTracker,
+    config::ProtocolConfig,
+    denylist::Denylist,
+    exemptions::Exemptions,
+    lockup::Lockup,
+    mint_activity::MintActivity,
+    mint_rules::MintRules,
+    order_tracker::{CircuitBreaker, OrderTracker},
+    pair_volume::PairVolume,
     profile::Profile,
+    program_filter::{ProgramFilter, ProgramFilterMode},
+    rolling_volume::RollingVolume,
     soulbound::{MintAuthority, Soulbound},
+    trading_schedule::{TradingOverride, TradingSchedule, TradingWindow},
+    transfer_history::{TransferHistory, TransferHistoryEntry},
+    transfer_permit::TransferPermit,
+    wallet_link::WalletLink,
 };
@@ -1,22 +1,64 @@
+#[allow(deprecated)]
+use solana_program::borsh0_10::get_instance_packed_len;
 use {
     borsh::{BorshDeserialize, BorshSerialize},
-    solana_program::pubkey::Pubkey,
+    solana_program::{instruction::Instruction, pubkey::Pubkey, sysvar::Sysvar},
     std::collections::HashMap,
 };
 
+/// A mint's circuit breaker, set by the protocol admin, which halts the mint
+/// when its volume within a slot window exceeds the breaker's threshold.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, Default, PartialEq)]
+pub struct CircuitBreaker {
+    /// The volume within a window above which the mint is halted.
+    pub threshold: u64,
+    /// The length of a window, in slots.
+    pub window_slots: u64,
+    /// The slot at which the current window started.
+    pub window_start_slot: u64,
+    /// The volume within the current window.
+    pub window_volume: u64,
+    /// Whether the mint is halted until the protocol admin resets it.
+    pub halted: bool,
+}
+
 /// Tracks the volume of orders on mints.
 #[derive(BorshDeserialize, BorshSerialize, Debug, Default)]
 pub struct OrderTracker {
     /// The volume of orders for each mint.
     pub volume: HashMap<Pubkey, u64>,
+    /// The circuit breaker for each mint that has one enabled.
+    pub circuit_breakers: HashMap<Pubkey, CircuitBreaker>,
 }
 
 impl OrderTracker {
     pub fn seed<'s>() -> &'s [u8] {
         b"order_tracker"
     }
-    pub fn address() -> Pubkey {
-        Pubkey::find_program_address(&[Self::seed()], &crate::id()).0
+
+    pub fn address_with_bump(namespace: &Pubkey) -> (Pubkey, u8) {
+        Self::address_with_bump_and_program_id(namespace, &crate::id())
+    }
+
+    pub fn address_with_bump_and_program_id(
+        namespace: &Pubkey,
+        program_id: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::seed(), namespace.as_ref()], program_id)
+    }
+
+    pub fn address(namespace: &Pubkey) -> Pubkey {
+        Self::address_with_program_id(namespace, &crate::id())
+    }
+
+    pub fn address_with_program_id(namespace: &Pubkey, program_id: &Pubkey) -> Pubkey {
+        Self::address_with_bump_and_program_id(namespace, program_id).0
+    }
+
+    /// Registers a mint, so the transfer hook never grows the order tracker
+    /// and the mint's creator pays for the mint's volume entry.
+    pub fn register(&mut self, mint: &Pubkey) {
+        self.volume.entry(*mint).or_insert(0);
     }
 
     /// Increment the volume of orders for a mint.
@@ -24,4 +66,69 @@ impl OrderTracker {
         let volume = self.volume.entry(*mint).or_insert(0);
         *volume += amount;
     }
+
+    /// Whether a mint's circuit breaker has halted the mint.
+    pub fn is_halted(&self, mint: &Pubkey) -> bool {
+        self.circuit_breakers
+            .get(mint)
+            .is_some_and(|breaker| breaker.halted)
+    }
+
+    /// Sets a mint's circuit breaker threshold and window, keeping its current
+    /// window and halt. A zero threshold removes the breaker, lifting any halt.
+    pub fn set_circuit_breaker(&mut self, mint: &Pubkey, threshold: u64, window_slots: u64) {
+        if threshold == 0 {
+            self.circuit_breakers.remove(mint);
+            return;
+        }
+        let breaker = self.circuit_breakers.entry(*mint).or_default();
+        breaker.threshold = threshold;
+        breaker.window_slots = window_slots;
+    }
+
+    /// Records volume for a mint's circuit breaker, if it has one, at the
+    /// given slot, starting a new window if the current one has elapsed, and
+    /// halts the mint if the window's volume exceeds the threshold.
+    pub fn record_circuit_breaker_volume(&mut self, mint: &Pubkey, amount: u64, slot: u64) {
+        let Some(breaker) = self.circuit_breakers.get_mut(mint) else {
+            return;
+        };
+        if slot.saturating_sub(breaker.window_start_slot) >= breaker.window_slots {
+            breaker.window_start_slot = slot;
+            breaker.window_volume = 0;
+        }
+        breaker.window_volume = breaker.window_volume.saturating_add(amount);
+        if breaker.window_volume > breaker.threshold {
+            breaker.halted = true;
+        }
+    }
+
+    /// Lifts a mint's halt and clears its current window, keeping its
+    /// threshold and window length.
+    pub fn reset_circuit_breaker(&mut self, mint: &Pubkey) {
+        if let Some(breaker) = self.circuit_breakers.get_mut(mint) {
+            breaker.window_start_slot = 0;
+            breaker.window_volume = 0;
+            breaker.halted = false;
+        }
+    }
+
+    pub fn create_account_instruction(
+        payer_address: &Pubkey,
+        namespace: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
+        #[allow(deprecated)]
+        let space = get_instance_packed_len(&Self::default()).unwrap();
+        let lamports = solana_program::rent::Rent::get()
+            .unwrap()
+            .minimum_balance(space);
+        solana_program::system_instruction::create_account(
+            payer_address,
+            &Self::address_with_program_id(namespace, program_id),
+            lamports,
+            space as u64,
+            program_id,
+        )
+    }
 }
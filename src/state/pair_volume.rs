@@ -0,0 +1,94 @@
+use {
+    super::RollingVolume,
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{instruction::Instruction, pubkey::Pubkey, sysvar::Sysvar},
+};
+
+/// The volume of a protocol mint sent from one primary wallet, and the wallets
+/// linked to it, to another within the sliding wash trading window.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Default)]
+pub struct PairVolume {
+    /// The volume sent within the window.
+    pub volume: RollingVolume,
+}
+
+impl PairVolume {
+    pub const LEN: usize = RollingVolume::LEN;
+
+    pub fn seed<'s>() -> &'s [u8] {
+        b"pair_volume"
+    }
+
+    pub fn address_with_bump(
+        source_wallet: &Pubkey,
+        destination_wallet: &Pubkey,
+        mint: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Self::address_with_bump_and_program_id(
+            source_wallet,
+            destination_wallet,
+            mint,
+            &crate::id(),
+        )
+    }
+
+    pub fn address_with_bump_and_program_id(
+        source_wallet: &Pubkey,
+        destination_wallet: &Pubkey,
+        mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                Self::seed(),
+                source_wallet.as_ref(),
+                destination_wallet.as_ref(),
+                mint.as_ref(),
+            ],
+            program_id,
+        )
+    }
+
+    pub fn address(source_wallet: &Pubkey, destination_wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+        Self::address_with_program_id(source_wallet, destination_wallet, mint, &crate::id())
+    }
+
+    pub fn address_with_program_id(
+        source_wallet: &Pubkey,
+        destination_wallet: &Pubkey,
+        mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Pubkey {
+        Self::address_with_bump_and_program_id(source_wallet, destination_wallet, mint, program_id)
+            .0
+    }
+
+    /// Returns the volume sent within the window ending at the given time.
+    pub fn volume_at(&self, unix_timestamp: i64, window: i64) -> u64 {
+        self.volume.volume_at(unix_timestamp, window)
+    }
+
+    /// Records volume sent at the given time.
+    pub fn record(&mut self, amount: u64, unix_timestamp: i64, window: i64) {
+        self.volume.record(amount, unix_timestamp, window);
+    }
+
+    pub fn create_account_instruction(
+        payer_address: &Pubkey,
+        source_wallet: &Pubkey,
+        destination_wallet: &Pubkey,
+        mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
+        let lamports = solana_program::rent::Rent::get()
+            .unwrap()
+            .minimum_balance(Self::LEN);
+        solana_program::system_instruction::create_account(
+            payer_address,
+            &Self::address_with_program_id(source_wallet, destination_wallet, mint, program_id),
+            lamports,
+            Self::LEN as u64,
+            program_id,
+        )
+    }
+}
@@ -8,21 +8,52 @@ use {
 pub struct Profile {
     pub wallet_address: Pubkey,
     pub order_volume: u64,
+    /// The volume the user has transferred as a delegate of other owners.
+    pub delegated_volume: u64,
+    /// The volume the user has transferred between their own accounts, which
+    /// is excluded from the order volume.
+    pub self_transfer_volume: u64,
+    /// Whether the protocol admin has frozen this profile.
+    pub frozen: bool,
+    /// Whether the user has been flagged for round-trip trading with another
+    /// profile.
+    pub wash_trading_flagged: bool,
+    /// The number of secondary wallets linked to this profile.
+    pub linked_wallets: u32,
 }
 
 impl Profile {
-    pub const LEN: usize = 32 + 8;
+    pub const LEN: usize = 32 + 8 + 8 + 8 + 1 + 1 + 4;
 
     pub fn seed<'s>() -> &'s [u8] {
         b"profile"
     }
 
-    pub fn address_with_bump(wallet_address: &Pubkey) -> (Pubkey, u8) {
-        Pubkey::find_program_address(&[Self::seed(), wallet_address.as_ref()], &crate::id())
+    pub fn address_with_bump(namespace: &Pubkey, wallet_address: &Pubkey) -> (Pubkey, u8) {
+        Self::address_with_bump_and_program_id(namespace, wallet_address, &crate::id())
     }
 
-    pub fn address(wallet_address: &Pubkey) -> Pubkey {
-        Self::address_with_bump(wallet_address).0
+    pub fn address_with_bump_and_program_id(
+        namespace: &Pubkey,
+        wallet_address: &Pubkey,
+        program_id: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::seed(), namespace.as_ref(), wallet_address.as_ref()],
+            program_id,
+        )
+    }
+
+    pub fn address(namespace: &Pubkey, wallet_address: &Pubkey) -> Pubkey {
+        Self::address_with_program_id(namespace, wallet_address, &crate::id())
+    }
+
+    pub fn address_with_program_id(
+        namespace: &Pubkey,
+        wallet_address: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Pubkey {
+        Self::address_with_bump_and_program_id(namespace, wallet_address, program_id).0
     }
 
     pub fn new(wallet_address: &Pubkey) -> Self {
@@ -30,19 +61,42 @@ impl Profile {
         Self {
             wallet_address,
             order_volume: 0,
+            delegated_volume: 0,
+            self_transfer_volume: 0,
+            frozen: false,
+            wash_trading_flagged: false,
+            linked_wallets: 0,
         }
     }
 
-    pub fn create_account_instruction(wallet_address: &Pubkey) -> Instruction {
+    pub fn create_account_instruction(
+        namespace: &Pubkey,
+        wallet_address: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
+        Self::create_account_instruction_with_payer(
+            wallet_address,
+            namespace,
+            wallet_address,
+            program_id,
+        )
+    }
+
+    pub fn create_account_instruction_with_payer(
+        payer_address: &Pubkey,
+        namespace: &Pubkey,
+        wallet_address: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
         let lamports = solana_program::rent::Rent::get()
             .unwrap()
             .minimum_balance(Self::LEN);
         solana_program::system_instruction::create_account(
-            wallet_address,
-            &Self::address(wallet_address),
+            payer_address,
+            &Self::address_with_program_id(namespace, wallet_address, program_id),
             lamports,
             Self::LEN as u64,
-            &crate::id(),
+            program_id,
         )
     }
 }
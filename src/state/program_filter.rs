@@ -0,0 +1,77 @@
+use {
+    super::pubkey_set::PubkeySet,
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{instruction::Instruction, pubkey::Pubkey, sysvar::Sysvar},
+};
+
+/// How a program filter treats the programs it lists.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, Default, PartialEq)]
+pub enum ProgramFilterMode {
+    /// Listed programs may not initiate transfers.
+    #[default]
+    Denylist,
+    /// Only listed programs may initiate transfers.
+    Allowlist,
+}
+
+/// The top-level programs that may initiate transfers of a protocol mint,
+/// such as Token-2022 itself or a settlement program. Managed by the mint
+/// authority. A mint without a filter may be transferred by any program.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Default)]
+pub struct ProgramFilter {
+    pub mode: ProgramFilterMode,
+    pub program_ids: PubkeySet,
+}
+
+impl ProgramFilter {
+    pub fn seed<'s>() -> &'s [u8] {
+        b"program_filter"
+    }
+
+    pub fn address_with_bump(mint: &Pubkey) -> (Pubkey, u8) {
+        Self::address_with_bump_and_program_id(mint, &crate::id())
+    }
+
+    pub fn address_with_bump_and_program_id(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::seed(), mint.as_ref()], program_id)
+    }
+
+    pub fn address(mint: &Pubkey) -> Pubkey {
+        Self::address_with_program_id(mint, &crate::id())
+    }
+
+    pub fn address_with_program_id(mint: &Pubkey, program_id: &Pubkey) -> Pubkey {
+        Self::address_with_bump_and_program_id(mint, program_id).0
+    }
+
+    pub fn packed_len(&self) -> usize {
+        1 + PubkeySet::packed_len(self.program_ids.len())
+    }
+
+    /// Whether the given top-level program may initiate a transfer.
+    pub fn permits(&self, program_id: &Pubkey) -> bool {
+        let listed = self.program_ids.contains(program_id);
+        match self.mode {
+            ProgramFilterMode::Denylist => !listed,
+            ProgramFilterMode::Allowlist => listed,
+        }
+    }
+
+    pub fn create_account_instruction(
+        payer_address: &Pubkey,
+        mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
+        let space = Self::default().packed_len();
+        let lamports = solana_program::rent::Rent::get()
+            .unwrap()
+            .minimum_balance(space);
+        solana_program::system_instruction::create_account(
+            payer_address,
+            &Self::address_with_program_id(mint, program_id),
+            lamports,
+            space as u64,
+            program_id,
+        )
+    }
+}
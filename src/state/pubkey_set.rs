@@ -0,0 +1,75 @@
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{program_error::ProgramError, pubkey::Pubkey},
+};
+
+/// A set of public keys, kept sorted for binary search.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, Default, PartialEq)]
+pub struct PubkeySet(Vec<Pubkey>);
+
+impl PubkeySet {
+    /// The packed length of a set holding `len` keys.
+    pub fn packed_len(len: usize) -> usize {
+        4 + 32 * len
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains(&self, key: &Pubkey) -> bool {
+        self.0.binary_search(key).is_ok()
+    }
+
+    /// Inserts a key, returning `false` if it was already present.
+    pub fn insert(&mut self, key: &Pubkey) -> bool {
+        match self.0.binary_search(key) {
+            Ok(_) => false,
+            Err(index) => {
+                self.0.insert(index, *key);
+                true
+            }
+        }
+    }
+
+    /// Removes a key, returning `false` if it was not present.
+    pub fn remove(&mut self, key: &Pubkey) -> bool {
+        match self.0.binary_search(key) {
+            Ok(index) => {
+                self.0.remove(index);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Pubkey> {
+        self.0.iter()
+    }
+
+    /// Looks up a key in a packed set without deserializing it, by binary
+    /// searching the sorted keys in place.
+    pub fn packed_contains(data: &[u8], key: &Pubkey) -> Result<bool, ProgramError> {
+        let len = data
+            .get(..4)
+            .map(|len| u32::from_le_bytes(len.try_into().unwrap()) as usize)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let keys = data
+            .get(4..Self::packed_len(len))
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let (mut low, mut high) = (0, len);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match keys[32 * mid..32 * (mid + 1)].cmp(key.as_ref()) {
+                std::cmp::Ordering::Less => low = mid + 1,
+                std::cmp::Ordering::Greater => high = mid,
+                std::cmp::Ordering::Equal => return Ok(true),
+            }
+        }
+        Ok(false)
+    }
+}
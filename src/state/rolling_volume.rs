@@ -0,0 +1,71 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Volume summed over a sliding window, kept in fixed-length buckets so that
+/// volume leaves the window one bucket at a time rather than all at once when
+/// a window elapses.
+///
+/// The window is split into `BUCKETS` buckets. Buckets are counted from the
+/// Unix epoch, so the oldest bucket still within the window may have partly
+/// elapsed, and the volume covers up to one bucket more than the window.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct RollingVolume {
+    /// The length of each bucket, in seconds, or zero if nothing has been
+    /// recorded yet.
+    pub bucket_length: i64,
+    /// The index of the most recent bucket volume was recorded in.
+    pub last_bucket: i64,
+    /// The volume recorded in each of the most recent buckets, indexed by
+    /// bucket index modulo `BUCKETS`.
+    pub buckets: [u64; RollingVolume::BUCKETS],
+}
+
+impl RollingVolume {
+    pub const BUCKETS: usize = 24;
+
+    pub const LEN: usize = 8 + 8 + 8 * Self::BUCKETS;
+
+    fn bucket_length(window: i64) -> i64 {
+        (window / Self::BUCKETS as i64).max(1)
+    }
+
+    /// Returns the volume within the window ending at the given time.
+    pub fn volume_at(&self, unix_timestamp: i64, window: i64) -> u64 {
+        let bucket_length = Self::bucket_length(window);
+        // Buckets recorded under a different window can't be compared.
+        if self.bucket_length != bucket_length {
+            return 0;
+        }
+        let bucket = unix_timestamp.div_euclid(bucket_length);
+        let elapsed = bucket.saturating_sub(self.last_bucket).max(0);
+        (0..Self::BUCKETS as i64)
+            .take_while(|age| age + elapsed < Self::BUCKETS as i64)
+            .map(|age| self.buckets[Self::index(self.last_bucket - age)])
+            .fold(0, u64::saturating_add)
+    }
+
+    /// Records volume at the given time, clearing the buckets that have left
+    /// the window since volume was last recorded.
+    pub fn record(&mut self, amount: u64, unix_timestamp: i64, window: i64) {
+        let bucket_length = Self::bucket_length(window);
+        if self.bucket_length != bucket_length {
+            *self = Self {
+                bucket_length,
+                ..Self::default()
+            };
+        }
+        let bucket = unix_timestamp.div_euclid(bucket_length);
+        if bucket > self.last_bucket {
+            let elapsed = bucket.saturating_sub(self.last_bucket);
+            for age in 0..elapsed.min(Self::BUCKETS as i64) {
+                self.buckets[Self::index(bucket - age)] = 0;
+            }
+            self.last_bucket = bucket;
+        }
+        let index = Self::index(self.last_bucket);
+        self.buckets[index] = self.buckets[index].saturating_add(amount);
+    }
+
+    fn index(bucket: i64) -> usize {
+        bucket.rem_euclid(Self::BUCKETS as i64) as usize
+    }
+}
@@ -1,7 +1,7 @@
 use {
-    solana_program::{instruction::Instruction, pubkey::Pubkey},
+    solana_program::{instruction::Instruction, pubkey::Pubkey, sysvar::Sysvar},
     spl_associated_token_account::get_associated_token_address_with_program_id,
-    spl_token_2022::ID as TOKEN_2022_PROGRAM_ID,
+    spl_token_2022::{extension::ExtensionType, state::Mint, ID as TOKEN_2022_PROGRAM_ID},
 };
 
 /// A soulbound token.
@@ -10,50 +10,140 @@ pub struct Soulbound;
 impl Soulbound {
     pub const DECIMALS: u8 = 0;
 
-    pub fn address() -> Pubkey {
-        Pubkey::find_program_address(&[b"soulbound"], &crate::id()).0
+    pub fn seed<'s>() -> &'s [u8] {
+        b"soulbound"
+    }
+
+    pub fn address_with_bump(namespace: &Pubkey) -> (Pubkey, u8) {
+        Self::address_with_bump_and_program_id(namespace, &crate::id())
+    }
+
+    pub fn address_with_bump_and_program_id(
+        namespace: &Pubkey,
+        program_id: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::seed(), namespace.as_ref()], program_id)
+    }
+
+    pub fn address(namespace: &Pubkey) -> Pubkey {
+        Self::address_with_program_id(namespace, &crate::id())
+    }
+
+    pub fn address_with_program_id(namespace: &Pubkey, program_id: &Pubkey) -> Pubkey {
+        Self::address_with_bump_and_program_id(namespace, program_id).0
+    }
+
+    /// Creates the soulbound mint's account, owned by Token-2022 and sized for
+    /// its non-transferable and permanent delegate extensions.
+    pub fn create_account_instruction(
+        payer_address: &Pubkey,
+        namespace: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
+        let space = ExtensionType::try_calculate_account_len::<Mint>(&[
+            ExtensionType::NonTransferable,
+            ExtensionType::PermanentDelegate,
+        ])
+        .unwrap();
+        let lamports = solana_program::rent::Rent::get()
+            .unwrap()
+            .minimum_balance(space);
+        solana_program::system_instruction::create_account(
+            payer_address,
+            &Self::address_with_program_id(namespace, program_id),
+            lamports,
+            space as u64,
+            &TOKEN_2022_PROGRAM_ID,
+        )
     }
 
     /// Get an associated token account address for the soulbound token.
-    pub fn token_account(wallet_address: &Pubkey) -> Pubkey {
+    pub fn token_account(namespace: &Pubkey, wallet_address: &Pubkey) -> Pubkey {
+        Self::token_account_with_program_id(namespace, wallet_address, &crate::id())
+    }
+
+    pub fn token_account_with_program_id(
+        namespace: &Pubkey,
+        wallet_address: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Pubkey {
         get_associated_token_address_with_program_id(
             wallet_address,
-            &Self::address(),
+            &Self::address_with_program_id(namespace, program_id),
             &TOKEN_2022_PROGRAM_ID,
         )
     }
 
-    pub fn initialize_non_transferrable_instruction() -> Instruction {
+    pub fn initialize_non_transferrable_instruction(
+        namespace: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
         spl_token_2022::instruction::initialize_non_transferable_mint(
             &spl_token_2022::id(),
-            &Self::address(),
+            &Self::address_with_program_id(namespace, program_id),
+        )
+        .unwrap()
+    }
+
+    /// The protocol's mint authority is the soulbound mint's permanent
+    /// delegate, allowing it to burn soulbound tokens during profile recovery.
+    pub fn initialize_permanent_delegate_instruction(
+        namespace: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
+        spl_token_2022::instruction::initialize_permanent_delegate(
+            &spl_token_2022::id(),
+            &Self::address_with_program_id(namespace, program_id),
+            &MintAuthority::address_with_program_id(namespace, program_id),
         )
         .unwrap()
     }
 
-    pub fn initialize_mint_instruction() -> Instruction {
+    pub fn initialize_mint_instruction(namespace: &Pubkey, program_id: &Pubkey) -> Instruction {
         spl_token_2022::instruction::initialize_mint2(
             &spl_token_2022::id(),
-            &Self::address(),
-            &MintAuthority::address(),
+            &Self::address_with_program_id(namespace, program_id),
+            &MintAuthority::address_with_program_id(namespace, program_id),
             None,
             Self::DECIMALS,
         )
         .unwrap()
     }
 
-    pub fn mint_to_instruction(wallet_address: &Pubkey) -> Instruction {
+    pub fn mint_to_instruction(
+        namespace: &Pubkey,
+        wallet_address: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
         spl_token_2022::instruction::mint_to_checked(
             &spl_token_2022::id(),
-            &Self::address(),
-            &Self::token_account(wallet_address),
-            &MintAuthority::address(),
+            &Self::address_with_program_id(namespace, program_id),
+            &Self::token_account_with_program_id(namespace, wallet_address, program_id),
+            &MintAuthority::address_with_program_id(namespace, program_id),
             &[],
             1,
             Self::DECIMALS,
         )
         .unwrap()
     }
+
+    pub fn burn_instruction(
+        namespace: &Pubkey,
+        wallet_address: &Pubkey,
+        amount: u64,
+        program_id: &Pubkey,
+    ) -> Instruction {
+        spl_token_2022::instruction::burn_checked(
+            &spl_token_2022::id(),
+            &Self::token_account_with_program_id(namespace, wallet_address, program_id),
+            &Self::address_with_program_id(namespace, program_id),
+            &MintAuthority::address_with_program_id(namespace, program_id),
+            &[],
+            amount,
+            Self::DECIMALS,
+        )
+        .unwrap()
+    }
 }
 
 /// The protocol's soulbound token Mint Authority.
@@ -64,11 +154,41 @@ impl MintAuthority {
         b"mint_authority"
     }
 
-    pub fn address_with_bump() -> (Pubkey, u8) {
-        Pubkey::find_program_address(&[Self::seed()], &crate::id())
+    pub fn address_with_bump(namespace: &Pubkey) -> (Pubkey, u8) {
+        Self::address_with_bump_and_program_id(namespace, &crate::id())
+    }
+
+    pub fn address_with_bump_and_program_id(
+        namespace: &Pubkey,
+        program_id: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::seed(), namespace.as_ref()], program_id)
+    }
+
+    pub fn address(namespace: &Pubkey) -> Pubkey {
+        Self::address_with_program_id(namespace, &crate::id())
+    }
+
+    pub fn address_with_program_id(namespace: &Pubkey, program_id: &Pubkey) -> Pubkey {
+        Self::address_with_bump_and_program_id(namespace, program_id).0
     }
 
-    pub fn address() -> Pubkey {
-        Self::address_with_bump().0
+    /// Funds the mint authority's account, owned by the program, so it exists
+    /// on chain alongside the namespace's soulbound mint.
+    pub fn create_account_instruction(
+        payer_address: &Pubkey,
+        namespace: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
+        let lamports = solana_program::rent::Rent::get()
+            .unwrap()
+            .minimum_balance(0);
+        solana_program::system_instruction::create_account(
+            payer_address,
+            &Self::address_with_program_id(namespace, program_id),
+            lamports,
+            0,
+            program_id,
+        )
     }
 }
@@ -0,0 +1,115 @@
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{instruction::Instruction, pubkey::Pubkey, sysvar::Sysvar},
+};
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// A recurring UTC window during which a protocol mint may be transferred.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub struct TradingWindow {
+    /// The days of the week the window applies to, as a bitmask where bit 0
+    /// is Sunday and bit 6 is Saturday.
+    pub days: u8,
+    /// The second of the UTC day at which the window opens.
+    pub open_second: u32,
+    /// The second of the UTC day at which the window closes, exclusive.
+    pub close_second: u32,
+}
+
+impl TradingWindow {
+    pub const LEN: usize = 1 + 4 + 4;
+
+    /// Whether the window is open at the given time.
+    pub fn contains(&self, unix_timestamp: i64) -> bool {
+        let days_since_epoch = unix_timestamp.div_euclid(SECONDS_PER_DAY);
+        // The Unix epoch fell on a Thursday.
+        let day_of_week = (days_since_epoch + 4).rem_euclid(7) as u8;
+        let second_of_day = unix_timestamp.rem_euclid(SECONDS_PER_DAY) as u32;
+        self.days & (1 << day_of_week) != 0
+            && self.open_second <= second_of_day
+            && second_of_day < self.close_second
+    }
+}
+
+/// An override of a protocol mint's trading windows, set by the protocol
+/// admin.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, Default, PartialEq)]
+pub enum TradingOverride {
+    /// Trading follows the mint's windows.
+    #[default]
+    None,
+    /// Trading is open regardless of the mint's windows.
+    Open,
+    /// Trading is closed regardless of the mint's windows.
+    Closed,
+}
+
+/// The times during which a protocol mint may be transferred. A mint without
+/// a schedule, or whose schedule has no windows, may be transferred at any
+/// time unless the protocol admin has closed trading.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Default)]
+pub struct TradingSchedule {
+    pub trading_override: TradingOverride,
+    /// The windows set by the mint authority.
+    pub windows: Vec<TradingWindow>,
+}
+
+impl TradingSchedule {
+    pub fn seed<'s>() -> &'s [u8] {
+        b"trading_schedule"
+    }
+
+    pub fn address_with_bump(mint: &Pubkey) -> (Pubkey, u8) {
+        Self::address_with_bump_and_program_id(mint, &crate::id())
+    }
+
+    pub fn address_with_bump_and_program_id(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::seed(), mint.as_ref()], program_id)
+    }
+
+    pub fn address(mint: &Pubkey) -> Pubkey {
+        Self::address_with_program_id(mint, &crate::id())
+    }
+
+    pub fn address_with_program_id(mint: &Pubkey, program_id: &Pubkey) -> Pubkey {
+        Self::address_with_bump_and_program_id(mint, program_id).0
+    }
+
+    pub fn packed_len(&self) -> usize {
+        1 + 4 + TradingWindow::LEN * self.windows.len()
+    }
+
+    /// Whether the mint may be transferred at the given time.
+    pub fn is_open(&self, unix_timestamp: i64) -> bool {
+        match self.trading_override {
+            TradingOverride::Open => true,
+            TradingOverride::Closed => false,
+            TradingOverride::None => {
+                self.windows.is_empty()
+                    || self
+                        .windows
+                        .iter()
+                        .any(|window| window.contains(unix_timestamp))
+            }
+        }
+    }
+
+    pub fn create_account_instruction(
+        payer_address: &Pubkey,
+        mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
+        let space = Self::default().packed_len();
+        let lamports = solana_program::rent::Rent::get()
+            .unwrap()
+            .minimum_balance(space);
+        solana_program::system_instruction::create_account(
+            payer_address,
+            &Self::address_with_program_id(mint, program_id),
+            lamports,
+            space as u64,
+            program_id,
+        )
+    }
+}
@@ -0,0 +1,115 @@
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{
+        instruction::Instruction, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    },
+};
+
+/// A transfer in a protocol mint's transfer history.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct TransferHistoryEntry {
+    /// The slot of the transfer.
+    pub slot: u64,
+    /// The owner of the source token account.
+    pub source_owner: Pubkey,
+    /// The owner of the destination token account.
+    pub destination_owner: Pubkey,
+    /// The amount transferred.
+    pub amount: u64,
+    /// The SHA-256 hash of the memo included with the transfer, or zeroes if
+    /// there was none.
+    pub memo_hash: [u8; 32],
+}
+
+/// The most recent transfers of a protocol mint, kept in a fixed-capacity
+/// ring buffer.
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub struct TransferHistory {
+    /// The number of transfers recorded since the mint was created. The next
+    /// transfer is written to `entries[count % CAPACITY]`.
+    pub count: u64,
+    /// The recorded transfers, overwritten oldest first once full.
+    pub entries: [TransferHistoryEntry; TransferHistory::CAPACITY],
+}
+
+impl Default for TransferHistory {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            entries: [TransferHistoryEntry::default(); Self::CAPACITY],
+        }
+    }
+}
+
+impl TransferHistory {
+    pub const CAPACITY: usize = 32;
+
+    const ENTRY_LEN: usize = 8 + 32 + 32 + 8 + 32;
+
+    pub const LEN: usize = 8 + Self::ENTRY_LEN * Self::CAPACITY;
+
+    /// Records a transfer, overwriting the oldest one if the history is full.
+    pub fn push(&mut self, entry: TransferHistoryEntry) {
+        self.entries[(self.count % Self::CAPACITY as u64) as usize] = entry;
+        self.count += 1;
+    }
+
+    /// Records a transfer in a packed history, writing only the count and the
+    /// overwritten entry rather than deserializing the whole history.
+    pub fn push_packed(data: &mut [u8], entry: &TransferHistoryEntry) -> Result<(), ProgramError> {
+        if data.len() < Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let (count, entries) = data.split_at_mut(8);
+        let index = u64::from_le_bytes(count.try_into().unwrap());
+        let offset = (index % Self::CAPACITY as u64) as usize * Self::ENTRY_LEN;
+        entry.serialize(&mut &mut entries[offset..offset + Self::ENTRY_LEN])?;
+        count.copy_from_slice(&(index + 1).to_le_bytes());
+        Ok(())
+    }
+
+    /// Returns the recorded transfers, most recent first.
+    pub fn recent(&self) -> Vec<TransferHistoryEntry> {
+        let len = self.count.min(Self::CAPACITY as u64) as usize;
+        (1..=len)
+            .map(|i| self.entries[((self.count - i as u64) % Self::CAPACITY as u64) as usize])
+            .collect()
+    }
+
+    pub fn seed<'s>() -> &'s [u8] {
+        b"transfer_history"
+    }
+
+    pub fn address_with_bump(mint: &Pubkey) -> (Pubkey, u8) {
+        Self::address_with_bump_and_program_id(mint, &crate::id())
+    }
+
+    pub fn address_with_bump_and_program_id(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::seed(), mint.as_ref()], program_id)
+    }
+
+    pub fn address(mint: &Pubkey) -> Pubkey {
+        Self::address_with_program_id(mint, &crate::id())
+    }
+
+    pub fn address_with_program_id(mint: &Pubkey, program_id: &Pubkey) -> Pubkey {
+        Self::address_with_bump_and_program_id(mint, program_id).0
+    }
+
+    pub fn create_account_instruction(
+        payer_address: &Pubkey,
+        mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
+        let lamports = solana_program::rent::Rent::get()
+            .unwrap()
+            .minimum_balance(Self::LEN);
+        solana_program::system_instruction::create_account(
+            payer_address,
+            &Self::address_with_program_id(mint, program_id),
+            lamports,
+            Self::LEN as u64,
+            program_id,
+        )
+    }
+}
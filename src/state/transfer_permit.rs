@@ -0,0 +1,89 @@
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{instruction::Instruction, pubkey::Pubkey, sysvar::Sysvar},
+};
+
+/// A transfer between two token accounts of a protocol mint, pre-approved by
+/// the mint's permit approver. Consumed by the first transfer it covers.
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct TransferPermit {
+    /// The approver that issued the permit.
+    pub approver: Pubkey,
+    /// The maximum amount of the permitted transfer.
+    pub max_amount: u64,
+    /// The Unix timestamp after which the permit can no longer be used.
+    pub expiry: i64,
+    /// Whether the permit has been used.
+    pub consumed: bool,
+}
+
+impl TransferPermit {
+    pub const LEN: usize = 32 + 8 + 8 + 1;
+
+    pub fn seed<'s>() -> &'s [u8] {
+        b"transfer_permit"
+    }
+
+    pub fn address_with_bump(source: &Pubkey, destination: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+        Self::address_with_bump_and_program_id(source, destination, mint, &crate::id())
+    }
+
+    pub fn address_with_bump_and_program_id(
+        source: &Pubkey,
+        destination: &Pubkey,
+        mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                Self::seed(),
+                source.as_ref(),
+                destination.as_ref(),
+                mint.as_ref(),
+            ],
+            program_id,
+        )
+    }
+
+    pub fn address(source: &Pubkey, destination: &Pubkey, mint: &Pubkey) -> Pubkey {
+        Self::address_with_program_id(source, destination, mint, &crate::id())
+    }
+
+    pub fn address_with_program_id(
+        source: &Pubkey,
+        destination: &Pubkey,
+        mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Pubkey {
+        Self::address_with_bump_and_program_id(source, destination, mint, program_id).0
+    }
+
+    pub fn new(approver: &Pubkey, max_amount: u64, expiry: i64) -> Self {
+        let approver = *approver;
+        Self {
+            approver,
+            max_amount,
+            expiry,
+            consumed: false,
+        }
+    }
+
+    pub fn create_account_instruction(
+        payer_address: &Pubkey,
+        source: &Pubkey,
+        destination: &Pubkey,
+        mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
+        let lamports = solana_program::rent::Rent::get()
+            .unwrap()
+            .minimum_balance(Self::LEN);
+        solana_program::system_instruction::create_account(
+            payer_address,
+            &Self::address_with_program_id(source, destination, mint, program_id),
+            lamports,
+            Self::LEN as u64,
+            program_id,
+        )
+    }
+}
@@ -1,6 +1,9 @@
 use {
-    super::{OrderTracker, Profile, Soulbound},
-    solana_program::{program_error::ProgramError, pubkey::Pubkey},
+    super::{
+        Denylist, Exemptions, Lockup, MintActivity, MintRules, OrderTracker, PairVolume, Profile,
+        ProgramFilter, TradingSchedule, TransferHistory, TransferPermit, WalletLink,
+    },
+    solana_program::{program_error::ProgramError, pubkey::Pubkey, sysvar},
     spl_tlv_account_resolution::{
         account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
     },
@@ -12,14 +15,18 @@ use {
 pub struct ValidationData;
 
 impl ValidationData {
-    pub const NUM_EXTRA_ACCOUNTS: usize = 8;
+    pub const NUM_EXTRA_ACCOUNTS: usize = 23;
 
     pub fn get_len() -> usize {
         ExtraAccountMetaList::size_of(Self::NUM_EXTRA_ACCOUNTS).unwrap()
     }
 
     pub fn address(mint: &Pubkey) -> Pubkey {
-        get_extra_account_metas_address(mint, &crate::id())
+        Self::address_with_program_id(mint, &crate::id())
+    }
+
+    pub fn address_with_program_id(mint: &Pubkey, program_id: &Pubkey) -> Pubkey {
+        get_extra_account_metas_address(mint, program_id)
     }
 
     fn extra_metas() -> [ExtraAccountMeta; Self::NUM_EXTRA_ACCOUNTS] {
@@ -29,83 +36,381 @@ impl ValidationData {
             // 6: Associated Token Program
             ExtraAccountMeta::new_with_pubkey(&spl_associated_token_account::id(), false, false)
                 .unwrap(),
-            // 7: Soulbound Mint
-            ExtraAccountMeta::new_with_pubkey(&Soulbound::address(), false, false).unwrap(),
-            // 8: Source Owner Soulbound Token Account
+            // 7: Mint Rules
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: MintRules::seed().to_vec(),
+                    },
+                    Seed::AccountKey {
+                        index: 1, // Mint
+                    },
+                ],
+                false,
+                false,
+            )
+            .unwrap(),
+            // 8: Source Owner Wallet Link
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: WalletLink::seed().to_vec(),
+                    },
+                    Seed::AccountData {
+                        account_index: 7, // Mint Rules
+                        data_index: MintRules::NAMESPACE_OFFSET,
+                        length: 32, // length of public key
+                    },
+                    // Reads the token account owner from the account's data.
+                    // See: https://docs.rs/spl-token-2022/latest/spl_token_2022/state/struct.Account.html
+                    Seed::AccountData {
+                        account_index: 0, // Source (token account)
+                        data_index: 32,   // `owner` field
+                        length: 32,       // length of public key
+                    },
+                ],
+                false,
+                false,
+            )
+            .unwrap(),
+            // 9: Source Primary Wallet Credential Token Account
             ExtraAccountMeta::new_external_pda_with_seeds(
                 6, // Associated Token Program
                 &[
-                    Seed::AccountKey {
-                        index: 3, // Source Owner
+                    Seed::AccountData {
+                        account_index: 8, // Source Owner Wallet Link
+                        data_index: 0,    // `primary_wallet` field
+                        length: 32,       // length of public key
                     },
                     Seed::AccountKey {
                         index: 5, // Token-2022 Program
                     },
-                    Seed::AccountKey {
-                        index: 7, // Soulbound Mint
+                    Seed::AccountData {
+                        account_index: 7, // Mint Rules
+                        data_index: MintRules::CREDENTIAL_MINT_OFFSET,
+                        length: 32, // length of public key
                     },
                 ],
                 false,
                 false,
             )
             .unwrap(),
-            // 9: Source Owner Profile
+            // 10: Source Primary Wallet Profile
             ExtraAccountMeta::new_with_seeds(
                 &[
                     Seed::Literal {
                         bytes: Profile::seed().to_vec(),
                     },
-                    Seed::AccountKey {
-                        index: 3, // Source Owner (wallet)
+                    Seed::AccountData {
+                        account_index: 7, // Mint Rules
+                        data_index: MintRules::NAMESPACE_OFFSET,
+                        length: 32, // length of public key
+                    },
+                    Seed::AccountData {
+                        account_index: 8, // Source Owner Wallet Link
+                        data_index: 0,    // `primary_wallet` field
+                        length: 32,       // length of public key
                     },
                 ],
                 false,
                 true, // writable
             )
             .unwrap(),
-            // 10: Destination Owner Soulbound Token Account
-            ExtraAccountMeta::new_external_pda_with_seeds(
-                6, // Associated Token Program
+            // 11: Destination Owner Wallet Link
+            ExtraAccountMeta::new_with_seeds(
                 &[
-                    // Reads the token account owner from the account's data.
-                    // See: https://docs.rs/spl-token-2022/latest/spl_token_2022/state/struct.Account.html
+                    Seed::Literal {
+                        bytes: WalletLink::seed().to_vec(),
+                    },
+                    Seed::AccountData {
+                        account_index: 7, // Mint Rules
+                        data_index: MintRules::NAMESPACE_OFFSET,
+                        length: 32, // length of public key
+                    },
                     Seed::AccountData {
                         account_index: 2, // Destination (token account)
                         data_index: 32,   // `owner` field
                         length: 32,       // length of public key
                     },
+                ],
+                false,
+                false,
+            )
+            .unwrap(),
+            // 12: Destination Primary Wallet Credential Token Account
+            ExtraAccountMeta::new_external_pda_with_seeds(
+                6, // Associated Token Program
+                &[
+                    Seed::AccountData {
+                        account_index: 11, // Destination Owner Wallet Link
+                        data_index: 0,     // `primary_wallet` field
+                        length: 32,        // length of public key
+                    },
                     Seed::AccountKey {
                         index: 5, // Token-2022 Program
                     },
-                    Seed::AccountKey {
-                        index: 7, // Soulbound Mint
+                    Seed::AccountData {
+                        account_index: 7, // Mint Rules
+                        data_index: MintRules::CREDENTIAL_MINT_OFFSET,
+                        length: 32, // length of public key
                     },
                 ],
                 false,
                 false,
             )
             .unwrap(),
-            // 11: Destination Owner Profile
+            // 13: Destination Primary Wallet Profile
             ExtraAccountMeta::new_with_seeds(
                 &[
                     Seed::Literal {
                         bytes: Profile::seed().to_vec(),
                     },
                     Seed::AccountData {
-                        account_index: 2, // Destination (token account)
+                        account_index: 7, // Mint Rules
+                        data_index: MintRules::NAMESPACE_OFFSET,
+                        length: 32, // length of public key
+                    },
+                    Seed::AccountData {
+                        account_index: 11, // Destination Owner Wallet Link
+                        data_index: 0,     // `primary_wallet` field
+                        length: 32,        // length of public key
+                    },
+                ],
+                false,
+                true, // writable
+            )
+            .unwrap(),
+            // 14: Order Tracker
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: OrderTracker::seed().to_vec(),
+                    },
+                    Seed::AccountData {
+                        account_index: 7, // Mint Rules
+                        data_index: MintRules::NAMESPACE_OFFSET,
+                        length: 32, // length of public key
+                    },
+                ],
+                false,
+                true, // writable
+            )
+            .unwrap(),
+            // 15: Denylist
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: Denylist::seed().to_vec(),
+                    },
+                    Seed::AccountData {
+                        account_index: 7, // Mint Rules
+                        data_index: MintRules::NAMESPACE_OFFSET,
+                        length: 32, // length of public key
+                    },
+                ],
+                false,
+                false,
+            )
+            .unwrap(),
+            // 16: Exemptions
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: Exemptions::seed().to_vec(),
+                    },
+                    Seed::AccountKey {
+                        index: 1, // Mint
+                    },
+                ],
+                false,
+                false,
+            )
+            .unwrap(),
+            // 17: Source Owner Lockup
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: Lockup::seed().to_vec(),
+                    },
+                    Seed::AccountData {
+                        account_index: 0, // Source (token account)
                         data_index: 32,   // `owner` field
                         length: 32,       // length of public key
                     },
+                    Seed::AccountKey {
+                        index: 1, // Mint
+                    },
+                ],
+                false,
+                false,
+            )
+            .unwrap(),
+            // 18: Trading Schedule
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: TradingSchedule::seed().to_vec(),
+                    },
+                    Seed::AccountKey {
+                        index: 1, // Mint
+                    },
+                ],
+                false,
+                false,
+            )
+            .unwrap(),
+            // 19: Transfer Authority Credential Token Account
+            ExtraAccountMeta::new_external_pda_with_seeds(
+                6, // Associated Token Program
+                &[
+                    Seed::AccountKey {
+                        index: 3, // Transfer Authority
+                    },
+                    Seed::AccountKey {
+                        index: 5, // Token-2022 Program
+                    },
+                    Seed::AccountData {
+                        account_index: 7, // Mint Rules
+                        data_index: MintRules::CREDENTIAL_MINT_OFFSET,
+                        length: 32, // length of public key
+                    },
+                ],
+                false,
+                false,
+            )
+            .unwrap(),
+            // 20: Transfer Authority Profile
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: Profile::seed().to_vec(),
+                    },
+                    Seed::AccountData {
+                        account_index: 7, // Mint Rules
+                        data_index: MintRules::NAMESPACE_OFFSET,
+                        length: 32, // length of public key
+                    },
+                    Seed::AccountKey {
+                        index: 3, // Transfer Authority
+                    },
+                ],
+                false,
+                true, // writable
+            )
+            .unwrap(),
+            // 21: Pair Volume (Source Primary Wallet to Destination Primary Wallet)
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: PairVolume::seed().to_vec(),
+                    },
+                    Seed::AccountData {
+                        account_index: 8, // Source Owner Wallet Link
+                        data_index: 0,    // `primary_wallet` field
+                        length: 32,       // length of public key
+                    },
+                    Seed::AccountData {
+                        account_index: 11, // Destination Owner Wallet Link
+                        data_index: 0,     // `primary_wallet` field
+                        length: 32,        // length of public key
+                    },
+                    Seed::AccountKey {
+                        index: 1, // Mint
+                    },
+                ],
+                false,
+                true, // writable
+            )
+            .unwrap(),
+            // 22: Pair Volume (Destination Primary Wallet to Source Primary Wallet)
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: PairVolume::seed().to_vec(),
+                    },
+                    Seed::AccountData {
+                        account_index: 11, // Destination Owner Wallet Link
+                        data_index: 0,     // `primary_wallet` field
+                        length: 32,        // length of public key
+                    },
+                    Seed::AccountData {
+                        account_index: 8, // Source Owner Wallet Link
+                        data_index: 0,    // `primary_wallet` field
+                        length: 32,       // length of public key
+                    },
+                    Seed::AccountKey {
+                        index: 1, // Mint
+                    },
+                ],
+                false,
+                false,
+            )
+            .unwrap(),
+            // 23: Transfer Permit
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: TransferPermit::seed().to_vec(),
+                    },
+                    Seed::AccountKey {
+                        index: 0, // Source
+                    },
+                    Seed::AccountKey {
+                        index: 2, // Destination
+                    },
+                    Seed::AccountKey {
+                        index: 1, // Mint
+                    },
                 ],
                 false,
                 true, // writable
             )
             .unwrap(),
-            // 12: Order Tracker
+            // 24: Instructions Sysvar
+            ExtraAccountMeta::new_with_pubkey(&sysvar::instructions::id(), false, false).unwrap(),
+            // 25: Program Filter
             ExtraAccountMeta::new_with_seeds(
-                &[Seed::Literal {
-                    bytes: OrderTracker::seed().to_vec(),
-                }],
+                &[
+                    Seed::Literal {
+                        bytes: ProgramFilter::seed().to_vec(),
+                    },
+                    Seed::AccountKey {
+                        index: 1, // Mint
+                    },
+                ],
+                false,
+                false,
+            )
+            .unwrap(),
+            // 26: Transfer History
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: TransferHistory::seed().to_vec(),
+                    },
+                    Seed::AccountKey {
+                        index: 1, // Mint
+                    },
+                ],
+                false,
+                true, // writable
+            )
+            .unwrap(),
+            // 27: Source Primary Wallet Mint Activity
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: MintActivity::seed().to_vec(),
+                    },
+                    Seed::AccountData {
+                        account_index: 8, // Source Owner Wallet Link
+                        data_index: 0,    // `primary_wallet` field
+                        length: 32,       // length of public key
+                    },
+                    Seed::AccountKey {
+                        index: 1, // Mint
+                    },
+                ],
                 false,
                 true, // writable
             )
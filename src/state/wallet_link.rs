@@ -0,0 +1,74 @@
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{instruction::Instruction, pubkey::Pubkey, sysvar::Sysvar},
+};
+
+/// Resolves a wallet to the primary wallet whose credential and profile it
+/// trades under.
+///
+/// A wallet with a profile is linked to itself, and a secondary wallet to its
+/// primary wallet. Other token account owners, such as exempt pool
+/// authorities, are linked to themselves so the transfer hook can resolve
+/// them.
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct WalletLink {
+    pub primary_wallet: Pubkey,
+}
+
+impl WalletLink {
+    pub const LEN: usize = 32;
+
+    pub fn seed<'s>() -> &'s [u8] {
+        b"wallet_link"
+    }
+
+    pub fn address_with_bump(namespace: &Pubkey, wallet_address: &Pubkey) -> (Pubkey, u8) {
+        Self::address_with_bump_and_program_id(namespace, wallet_address, &crate::id())
+    }
+
+    pub fn address_with_bump_and_program_id(
+        namespace: &Pubkey,
+        wallet_address: &Pubkey,
+        program_id: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::seed(), namespace.as_ref(), wallet_address.as_ref()],
+            program_id,
+        )
+    }
+
+    pub fn address(namespace: &Pubkey, wallet_address: &Pubkey) -> Pubkey {
+        Self::address_with_program_id(namespace, wallet_address, &crate::id())
+    }
+
+    pub fn address_with_program_id(
+        namespace: &Pubkey,
+        wallet_address: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Pubkey {
+        Self::address_with_bump_and_program_id(namespace, wallet_address, program_id).0
+    }
+
+    pub fn new(primary_wallet: &Pubkey) -> Self {
+        let primary_wallet = *primary_wallet;
+        Self { primary_wallet }
+    }
+
+    pub fn create_account_instruction(
+        payer_address: &Pubkey,
+        namespace: &Pubkey,
+        wallet_address: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Instruction {
+        let lamports = solana_program::rent::Rent::get()
+            .unwrap()
+            .minimum_balance(Self::LEN);
+        solana_program::system_instruction::create_account(
+            payer_address,
+            &Self::address_with_program_id(namespace, wallet_address, program_id),
+            lamports,
+            Self::LEN as u64,
+            program_id,
+        )
+    }
+}
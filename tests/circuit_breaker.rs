@@ -0,0 +1,128 @@
+#![cfg(feature = "test-sbf")]
+mod context;
+
+use {
+    borsh::BorshDeserialize,
+    context::{
+        setup, setup_empty_protocol_mint_account, setup_empty_protocol_validation_account,
+        setup_wallet, ProtocolTestContext,
+    },
+    order_tracker::{
+        error::ProtocolError,
+        state::{CircuitBreaker, MintRules, OrderTracker, DEFAULT_NAMESPACE},
+    },
+    solana_program_test::{tokio, ProgramTestContext},
+    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+const DECIMALS: u8 = 0;
+const NAME: &str = "Joe Token";
+const SYMBOL: &str = "JOE";
+const URI: &str = "https://www.joetoken.com";
+
+async fn setup_mint(context: &mut ProgramTestContext) -> Keypair {
+    let mint_authority = setup_wallet(context);
+    let mint = setup_empty_protocol_mint_account(context);
+    setup_empty_protocol_validation_account(context, &mint.pubkey());
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::create_mint(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &context.payer.pubkey(),
+                DECIMALS,
+                NAME,
+                SYMBOL,
+                URI,
+                &MintRules::default(),
+            )],
+            &[&mint_authority],
+        )
+        .await;
+
+    mint
+}
+
+async fn get_circuit_breaker(
+    context: &mut ProgramTestContext,
+    mint_address: &Pubkey,
+) -> Option<CircuitBreaker> {
+    let account = context
+        .banks_client
+        .get_account(OrderTracker::address(&DEFAULT_NAMESPACE))
+        .await
+        .unwrap()
+        .unwrap();
+    let order_tracker = OrderTracker::try_from_slice(&account.data).unwrap();
+    order_tracker.circuit_breakers.get(mint_address).cloned()
+}
+
+#[tokio::test]
+async fn fail_incorrect_admin() {
+    let mut context = setup().await;
+
+    let mint = setup_mint(&mut context).await;
+    let not_admin = setup_wallet(&mut context);
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::set_circuit_breaker(
+                &DEFAULT_NAMESPACE,
+                &not_admin.pubkey(),
+                &mint.pubkey(),
+                15,
+                100,
+            )],
+            &[&not_admin],
+            (0, ProtocolError::IncorrectAdmin),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success() {
+    let mut context = setup().await;
+
+    let mint = setup_mint(&mut context).await;
+    let admin = context.payer.pubkey();
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::set_circuit_breaker(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &mint.pubkey(),
+                15,
+                100,
+            )],
+            &[],
+        )
+        .await;
+    assert_eq!(
+        get_circuit_breaker(&mut context, &mint.pubkey()).await,
+        Some(CircuitBreaker {
+            threshold: 15,
+            window_slots: 100,
+            ..CircuitBreaker::default()
+        })
+    );
+
+    // A zero threshold removes the circuit breaker.
+    context
+        .expect_success(
+            &[order_tracker::instruction::set_circuit_breaker(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &mint.pubkey(),
+                0,
+                0,
+            )],
+            &[],
+        )
+        .await;
+    assert_eq!(
+        get_circuit_breaker(&mut context, &mint.pubkey()).await,
+        None
+    );
+}
@@ -3,8 +3,22 @@
 
 use {
     async_trait::async_trait,
-    order_tracker::state::{validation::ValidationData, MintAuthority, Soulbound},
-    solana_program::program_error::ProgramError,
+    base64::{engine::general_purpose::STANDARD, Engine},
+    borsh::BorshDeserialize,
+    order_tracker::{
+        events::{ProtocolEvent, VersionedEvent},
+        state::{
+            get_program_data_address, validation::ValidationData, Profile, Soulbound, WalletLink,
+            DEFAULT_NAMESPACE,
+        },
+    },
+    solana_program::{
+        account_info::AccountInfo,
+        bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+        entrypoint::ProgramResult,
+        program_error::ProgramError,
+        program_stubs::{set_syscall_stubs, SyscallStubs},
+    },
     solana_program_test::{
         processor, BanksClient, BanksClientError, ProgramTest, ProgramTestContext,
     },
@@ -25,12 +39,19 @@ use {
         },
         state::{Account as TokenAccount, AccountState, Mint},
     },
+    std::sync::Once,
 };
 
 pub async fn setup() -> ProgramTestContext {
+    setup_with_program_id(&order_tracker::id()).await
+}
+
+/// Starts a test validator with the protocol deployed under the given program
+/// ID, and initializes the protocol in the default namespace.
+pub async fn setup_with_program_id(program_id: &Pubkey) -> ProgramTestContext {
     let mut program_test = ProgramTest::new(
         "order_tracker",
-        order_tracker::id(),
+        *program_id,
         processor!(order_tracker::processor::process),
     );
     program_test.prefer_bpf(false);
@@ -40,48 +61,145 @@ pub async fn setup() -> ProgramTestContext {
         processor!(spl_token_2022::processor::Processor::process),
     );
 
-    // Add the soulbound mint authority.
-    program_test.add_account(
-        MintAuthority::address(),
-        Account {
-            lamports: 1_000_000_000,
-            owner: order_tracker::id(),
-            ..Account::default()
-        },
-    );
-    // Add the soulbound mint.
-    let account_size =
-        ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::NonTransferable])
-            .unwrap();
-    program_test.add_account(
-        Soulbound::address(),
-        Account {
-            lamports: 1_000_000_000,
-            data: vec![0; account_size],
-            owner: spl_token_2022::id(),
-            ..Account::default()
-        },
-    );
-    // Add the order tracker.
-    program_test.add_account(
-        order_tracker::state::OrderTracker::address(),
-        Account {
+    let mut context = program_test.start_with_context().await;
+    LOG_DATA_STUBS.call_once(|| {
+        let stubs = set_syscall_stubs(Box::new(LogDataStubs(None)));
+        set_syscall_stubs(Box::new(LogDataStubs(Some(stubs))));
+    });
+
+    // Deploy the program with the payer as its upgrade authority, which may
+    // claim the default namespace.
+    let payer = context.payer.insecure_clone();
+    context.set_account(
+        &get_program_data_address(program_id),
+        &Account {
             lamports: 1_000_000_000,
-            data: vec![0; 4], // Empty `HashMap`
-            owner: order_tracker::id(),
+            data: bincode::serialize(&UpgradeableLoaderState::ProgramData {
+                slot: 0,
+                upgrade_authority_address: Some(payer.pubkey()),
+            })
+            .unwrap(),
+            owner: bpf_loader_upgradeable::id(),
             ..Account::default()
-        },
+        }
+        .into(),
     );
+    setup_namespace_with_program_id(&mut context, &DEFAULT_NAMESPACE, &[], &payer, program_id)
+        .await;
 
-    let mut context = program_test.start_with_context().await;
+    context
+}
+
+/// The prefix of the log lines written by `LogDataStubs`.
+const LOG_DATA_PREFIX: &str = "Program log: data: ";
+
+static LOG_DATA_STUBS: Once = Once::new();
+
+/// The protocol runs natively under program-test, where `sol_log_data` prints
+/// to stdout instead of the transaction's logs. Wraps program-test's syscall
+/// stubs to log the data as `Program log: data: <base64>` instead, so tests
+/// can read the events a transaction emitted.
+struct LogDataStubs(Option<Box<dyn SyscallStubs>>);
+
+impl LogDataStubs {
+    fn inner(&self) -> &dyn SyscallStubs {
+        self.0.as_deref().unwrap()
+    }
+}
+
+impl SyscallStubs for LogDataStubs {
+    fn sol_log(&self, message: &str) {
+        self.inner().sol_log(message)
+    }
+    fn sol_log_compute_units(&self) {
+        self.inner().sol_log_compute_units()
+    }
+    fn sol_remaining_compute_units(&self) -> u64 {
+        self.inner().sol_remaining_compute_units()
+    }
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        self.inner()
+            .sol_invoke_signed(instruction, account_infos, signers_seeds)
+    }
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.inner().sol_get_clock_sysvar(var_addr)
+    }
+    fn sol_get_epoch_schedule_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.inner().sol_get_epoch_schedule_sysvar(var_addr)
+    }
+    fn sol_get_fees_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.inner().sol_get_fees_sysvar(var_addr)
+    }
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.inner().sol_get_rent_sysvar(var_addr)
+    }
+    fn sol_get_epoch_rewards_sysvar(&self, var_addr: *mut u8) -> u64 {
+        self.inner().sol_get_epoch_rewards_sysvar(var_addr)
+    }
+    fn sol_get_last_restart_slot(&self, var_addr: *mut u8) -> u64 {
+        self.inner().sol_get_last_restart_slot(var_addr)
+    }
+    fn sol_get_return_data(&self) -> Option<(Pubkey, Vec<u8>)> {
+        self.inner().sol_get_return_data()
+    }
+    fn sol_set_return_data(&self, data: &[u8]) {
+        self.inner().sol_set_return_data(data)
+    }
+    fn sol_log_data(&self, fields: &[&[u8]]) {
+        let fields = fields
+            .iter()
+            .map(|field| STANDARD.encode(field))
+            .collect::<Vec<_>>();
+        self.inner().sol_log(&format!("data: {}", fields.join(" ")))
+    }
+    fn sol_get_processed_sibling_instruction(&self, index: usize) -> Option<Instruction> {
+        self.inner().sol_get_processed_sibling_instruction(index)
+    }
+    fn sol_get_stack_height(&self) -> u64 {
+        self.inner().sol_get_stack_height()
+    }
+}
+
+/// Initializes the protocol within a namespace, claimed with the namespace's
+/// key, with the given wallet as the namespace's admin.
+pub async fn setup_namespace(
+    context: &mut ProgramTestContext,
+    namespace: &Keypair,
+    admin: &Keypair,
+) {
+    setup_namespace_with_program_id(
+        context,
+        &namespace.pubkey(),
+        &[namespace],
+        admin,
+        &order_tracker::id(),
+    )
+    .await;
+}
 
+pub async fn setup_namespace_with_program_id(
+    context: &mut ProgramTestContext,
+    namespace: &Pubkey,
+    namespace_signers: &[&Keypair],
+    admin: &Keypair,
+    program_id: &Pubkey,
+) {
     // Initialize the protocol.
     let transaction = Transaction::new_signed_with_payer(
-        &[order_tracker::instruction::initialize_protocol(
-            &context.payer.pubkey(),
-        )],
-        Some(&context.payer.pubkey()),
-        &[&context.payer],
+        &[
+            order_tracker::instruction::initialize_protocol_with_program_id(
+                namespace,
+                &admin.pubkey(),
+                program_id,
+            ),
+        ],
+        Some(&admin.pubkey()),
+        &[&[admin], namespace_signers].concat(),
         context.last_blockhash,
     );
     context
@@ -89,8 +207,6 @@ pub async fn setup() -> ProgramTestContext {
         .process_transaction(transaction)
         .await
         .unwrap();
-
-    context
 }
 
 pub fn setup_empty_protocol_mint_account(context: &mut ProgramTestContext) -> Keypair {
@@ -119,7 +235,19 @@ pub fn setup_empty_protocol_validation_account(
     context: &mut ProgramTestContext,
     mint_address: &Pubkey,
 ) {
-    let validation_address = ValidationData::address(mint_address);
+    setup_empty_protocol_validation_account_with_program_id(
+        context,
+        mint_address,
+        &order_tracker::id(),
+    );
+}
+
+pub fn setup_empty_protocol_validation_account_with_program_id(
+    context: &mut ProgramTestContext,
+    mint_address: &Pubkey,
+    program_id: &Pubkey,
+) {
+    let validation_address = ValidationData::address_with_program_id(mint_address, program_id);
     let account_size = ValidationData::get_len();
 
     context.set_account(
@@ -127,7 +255,7 @@ pub fn setup_empty_protocol_validation_account(
         &Account {
             data: vec![0; account_size],
             lamports: 1_000_000_000,
-            owner: order_tracker::id(),
+            owner: *program_id,
             ..Account::default()
         }
         .into(),
@@ -152,6 +280,31 @@ pub fn setup_soulbound_token_account(
     context: &mut ProgramTestContext,
     owner: &Pubkey,
     amount: u64,
+) {
+    setup_namespaced_soulbound_token_account(context, &DEFAULT_NAMESPACE, owner, amount);
+}
+
+pub fn setup_namespaced_soulbound_token_account(
+    context: &mut ProgramTestContext,
+    namespace: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+) {
+    setup_soulbound_token_account_with_program_id(
+        context,
+        namespace,
+        owner,
+        amount,
+        &order_tracker::id(),
+    );
+}
+
+pub fn setup_soulbound_token_account_with_program_id(
+    context: &mut ProgramTestContext,
+    namespace: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+    program_id: &Pubkey,
 ) {
     let account_size = ExtensionType::try_calculate_account_len::<TokenAccount>(&[
         ExtensionType::ImmutableOwner,
@@ -166,7 +319,7 @@ pub fn setup_soulbound_token_account(
         .init_extension::<NonTransferableAccount>(true)
         .unwrap();
     state.base = TokenAccount {
-        mint: Soulbound::address(),
+        mint: Soulbound::address_with_program_id(namespace, program_id),
         owner: *owner,
         amount,
         delegate: COption::None,
@@ -179,7 +332,7 @@ pub fn setup_soulbound_token_account(
     state.init_account_type().unwrap();
 
     context.set_account(
-        &Soulbound::token_account(owner),
+        &Soulbound::token_account_with_program_id(namespace, owner, program_id),
         &Account {
             lamports: 1_000_000_000,
             data: account_data,
@@ -196,11 +349,65 @@ pub fn setup_wallet_with_soulbound_token_account(context: &mut ProgramTestContex
     wallet
 }
 
+/// Creates a wallet with a soulbound token account and initializes its profile
+/// in the default namespace.
+pub async fn setup_profile(context: &mut ProgramTestContext) -> Keypair {
+    let wallet = setup_wallet_with_soulbound_token_account(context);
+    context
+        .expect_success(
+            &[order_tracker::instruction::initialize_profile(
+                &DEFAULT_NAMESPACE,
+                &wallet.pubkey(),
+            )],
+            &[&wallet],
+        )
+        .await;
+    wallet
+}
+
+pub async fn get_profile(context: &mut ProgramTestContext, wallet_address: &Pubkey) -> Profile {
+    let account = context
+        .banks_client
+        .get_account(Profile::address(&DEFAULT_NAMESPACE, wallet_address))
+        .await
+        .unwrap()
+        .unwrap();
+    Profile::try_from_slice(&account.data).unwrap()
+}
+
+pub async fn get_wallet_link(
+    context: &mut ProgramTestContext,
+    wallet_address: &Pubkey,
+) -> WalletLink {
+    let account = context
+        .banks_client
+        .get_account(WalletLink::address(&DEFAULT_NAMESPACE, wallet_address))
+        .await
+        .unwrap()
+        .unwrap();
+    WalletLink::try_from_slice(&account.data).unwrap()
+}
+
 #[async_trait]
 pub trait ProtocolTestContext {
     fn banks_client_mut(&mut self) -> &mut BanksClient;
     fn payer(&self) -> Keypair;
     fn last_blockhash(&self) -> Hash;
+    fn set_last_blockhash(&mut self, blockhash: Hash);
+
+    /// Fetches the latest blockhash. The test bank registers a new blockhash
+    /// every few milliseconds, so the one fetched during setup can age out of
+    /// the processing window in a long test under load. Transactions signed
+    /// with it then never land, and the banks client fails with a transport
+    /// error instead of the transaction result.
+    async fn refresh_blockhash(&mut self) {
+        let blockhash = self
+            .banks_client_mut()
+            .get_latest_blockhash()
+            .await
+            .unwrap();
+        self.set_last_blockhash(blockhash);
+    }
 
     fn default_transaction(
         &self,
@@ -232,10 +439,41 @@ pub trait ProtocolTestContext {
         instructions: &[Instruction],
         additional_signers: &[&Keypair],
     ) {
+        self.refresh_blockhash().await;
         let transaction = self.default_transaction(instructions, additional_signers);
         self.process_transaction(transaction).await.unwrap();
     }
 
+    /// Processes the instructions successfully, returning the protocol events
+    /// they emitted.
+    async fn expect_events(
+        &mut self,
+        instructions: &[Instruction],
+        additional_signers: &[&Keypair],
+    ) -> Vec<ProtocolEvent> {
+        self.refresh_blockhash().await;
+        let transaction = self.default_transaction(instructions, additional_signers);
+        let result = self
+            .banks_client_mut()
+            .process_transaction_with_metadata(transaction)
+            .await
+            .unwrap();
+        result.result.unwrap();
+        result
+            .metadata
+            .unwrap()
+            .log_messages
+            .iter()
+            .filter_map(|log| log.strip_prefix(LOG_DATA_PREFIX))
+            .map(|data| {
+                let data = STANDARD.decode(data).unwrap();
+                match VersionedEvent::try_from_slice(&data).unwrap() {
+                    VersionedEvent::V1(event) => event,
+                }
+            })
+            .collect()
+    }
+
     async fn expect_error<E: Into<ProgramError> + Send + Sync>(
         &mut self,
         instructions: &[Instruction],
@@ -244,6 +482,7 @@ pub trait ProtocolTestContext {
     ) {
         let (index, e) = expected;
         let expected_code = u64::from(e.into());
+        self.refresh_blockhash().await;
         let transaction = self.default_transaction(instructions, additional_signers);
         let err = self
             .process_transaction(transaction)
@@ -269,4 +508,8 @@ impl ProtocolTestContext for ProgramTestContext {
     fn last_blockhash(&self) -> Hash {
         self.last_blockhash
     }
+
+    fn set_last_blockhash(&mut self, blockhash: Hash) {
+        self.last_blockhash = blockhash;
+    }
 }
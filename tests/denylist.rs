@@ -0,0 +1,118 @@
+#![cfg(feature = "test-sbf")]
+mod context;
+
+use {
+    borsh::BorshDeserialize,
+    context::{setup, setup_wallet, ProtocolTestContext},
+    order_tracker::{
+        error::ProtocolError,
+        state::{Denylist, DEFAULT_NAMESPACE},
+    },
+    solana_program_test::{tokio, ProgramTestContext},
+    solana_sdk::{pubkey::Pubkey, signer::Signer},
+};
+
+async fn get_denylist(context: &mut ProgramTestContext) -> Denylist {
+    let account = context
+        .banks_client
+        .get_account(Denylist::address(&DEFAULT_NAMESPACE))
+        .await
+        .unwrap()
+        .unwrap();
+    Denylist::try_from_slice(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn fail_incorrect_admin() {
+    let mut context = setup().await;
+
+    let not_admin = setup_wallet(&mut context);
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::add_to_denylist(
+                &DEFAULT_NAMESPACE,
+                &not_admin.pubkey(),
+                &Pubkey::new_unique(),
+            )],
+            &[&not_admin],
+            (0, ProtocolError::IncorrectAdmin),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_wallet_already_denylisted() {
+    let mut context = setup().await;
+
+    let admin = context.payer.pubkey();
+    let wallet = Pubkey::new_unique();
+
+    context
+        .expect_error(
+            &[
+                order_tracker::instruction::add_to_denylist(&DEFAULT_NAMESPACE, &admin, &wallet),
+                order_tracker::instruction::add_to_denylist(&DEFAULT_NAMESPACE, &admin, &wallet),
+            ],
+            &[],
+            (1, ProtocolError::WalletAlreadyDenylisted),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_wallet_not_denylisted() {
+    let mut context = setup().await;
+
+    let admin = context.payer.pubkey();
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::remove_from_denylist(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &Pubkey::new_unique(),
+            )],
+            &[],
+            (0, ProtocolError::WalletNotDenylisted),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success() {
+    let mut context = setup().await;
+
+    let admin = context.payer.pubkey();
+    let wallet_a = Pubkey::new_unique();
+    let wallet_b = Pubkey::new_unique();
+
+    context
+        .expect_success(
+            &[
+                order_tracker::instruction::add_to_denylist(&DEFAULT_NAMESPACE, &admin, &wallet_a),
+                order_tracker::instruction::add_to_denylist(&DEFAULT_NAMESPACE, &admin, &wallet_b),
+            ],
+            &[],
+        )
+        .await;
+    let denylist = get_denylist(&mut context).await;
+    assert_eq!(denylist.wallets.len(), 2);
+    assert!(denylist.wallets.contains(&wallet_a));
+    assert!(denylist.wallets.contains(&wallet_b));
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::remove_from_denylist(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &wallet_a,
+            )],
+            &[],
+        )
+        .await;
+    let denylist = get_denylist(&mut context).await;
+    assert_eq!(denylist.wallets.len(), 1);
+    assert!(!denylist.wallets.contains(&wallet_a));
+    assert!(denylist.wallets.contains(&wallet_b));
+}
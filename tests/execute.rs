@@ -2,22 +2,32 @@
 mod context;
 
 use {
+    borsh::{BorshDeserialize, BorshSerialize},
     context::{
-        setup, setup_empty_protocol_mint_account, setup_empty_protocol_validation_account,
-        setup_soulbound_token_account, setup_wallet_with_soulbound_token_account,
-        ProtocolTestContext,
+        get_profile, setup, setup_empty_protocol_mint_account,
+        setup_empty_protocol_validation_account, setup_profile, setup_soulbound_token_account,
+        setup_wallet, setup_wallet_with_soulbound_token_account, ProtocolTestContext,
     },
     order_tracker::{
         error::ProtocolError,
-        state::{Profile, Soulbound},
+        events::{ProtocolEvent, VolumeTotals},
+        state::{
+            Lockup, MintActivity, MintRules, Profile, ProgramFilterMode, Soulbound,
+            TradingOverride, TradingWindow, TransferHistory, TransferHistoryEntry,
+            DEFAULT_NAMESPACE,
+        },
     },
     solana_program_test::{tokio, ProgramTestContext},
     solana_sdk::{
-        account::AccountSharedData, instruction::Instruction, pubkey::Pubkey, signature::Keypair,
-        signer::Signer,
+        account::AccountSharedData, clock::Clock, hash::hash, instruction::Instruction,
+        pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction,
     },
     spl_associated_token_account::get_associated_token_address_with_program_id,
-    spl_token_2022::offchain::{AccountDataResult, AccountFetchError},
+    spl_token_2022::{
+        extension::ExtensionType,
+        offchain::{AccountDataResult, AccountFetchError},
+        state::Mint,
+    },
     spl_transfer_hook_interface::{error::TransferHookError, get_extra_account_metas_address},
     std::cell::RefCell,
 };
@@ -73,14 +83,313 @@ impl ExecuteTestContext {
         instruction
     }
 
+    /// Creates a transfer from the destination back to the source, signed by
+    /// the destination owner.
+    async fn create_return_transfer_checked_instruction(&self, amount: u64) -> Instruction {
+        spl_token_2022::offchain::create_transfer_checked_instruction_with_extra_metas(
+            &spl_token_2022::id(),
+            &self.destination,
+            &self.mint.pubkey(),
+            &self.source,
+            &self.destination_owner.pubkey(),
+            &[&self.destination_owner.pubkey()],
+            amount,
+            DECIMALS,
+            |pubkey| self.get_account_data(pubkey),
+        )
+        .await
+        .unwrap()
+    }
+
+    /// Enables wash trading detection, flagging profiles once half of the
+    /// volume sent between them has come back within an hour.
+    async fn enable_wash_trading_detection(&mut self, block_wash_trading: bool) {
+        self.update_mint_rules(&MintRules {
+            wash_trade_ratio_bps: 5_000,
+            wash_trade_window: 60 * 60,
+            block_wash_trading,
+            ..MintRules::default()
+        })
+        .await;
+    }
+
+    async fn initialize_pair_volume(&mut self) {
+        let payer = self.context.payer.pubkey();
+        let mint = self.mint.pubkey();
+        let source_owner = self.source_owner.pubkey();
+        let destination_owner = self.destination_owner.pubkey();
+        self.context
+            .expect_success(
+                &[order_tracker::instruction::initialize_pair_volume(
+                    &payer,
+                    &mint,
+                    &source_owner,
+                    &destination_owner,
+                )],
+                &[],
+            )
+            .await;
+    }
+
+    async fn initialize_mint_activity(&mut self) {
+        let payer = self.context.payer.pubkey();
+        let mint = self.mint.pubkey();
+        let source_owner = self.source_owner.pubkey();
+        self.context
+            .expect_success(
+                &[order_tracker::instruction::initialize_mint_activity(
+                    &payer,
+                    &mint,
+                    &source_owner,
+                )],
+                &[],
+            )
+            .await;
+    }
+
+    async fn set_outflow_limit(&mut self, outflow_limit: u64) {
+        let admin = self.context.payer.pubkey();
+        let mint = self.mint.pubkey();
+        let source_owner = self.source_owner.pubkey();
+        self.context
+            .expect_success(
+                &[order_tracker::instruction::set_outflow_limit(
+                    &DEFAULT_NAMESPACE,
+                    &admin,
+                    &mint,
+                    &source_owner,
+                    outflow_limit,
+                )],
+                &[],
+            )
+            .await;
+    }
+
+    /// Creates another protocol mint, with the source owner as its mint
+    /// authority, and switches the transfers to it.
+    async fn switch_to_other_mint(&mut self) {
+        let mint = setup_empty_protocol_mint_account(&mut self.context);
+        setup_empty_protocol_validation_account(&mut self.context, &mint.pubkey());
+        let source_owner = self.source_owner.pubkey();
+        let destination_owner = self.destination_owner.pubkey();
+        let source = get_associated_token_address_with_program_id(
+            &source_owner,
+            &mint.pubkey(),
+            &spl_token_2022::id(),
+        );
+        let destination = get_associated_token_address_with_program_id(
+            &destination_owner,
+            &mint.pubkey(),
+            &spl_token_2022::id(),
+        );
+        let payer = self.context.payer.pubkey();
+        self.context
+            .expect_success(
+                &[
+                    order_tracker::instruction::create_mint(
+                        &mint.pubkey(),
+                        &source_owner,
+                        &payer,
+                        DECIMALS,
+                        NAME,
+                        SYMBOL,
+                        URI,
+                        &MintRules::default(),
+                    ),
+                    spl_associated_token_account::instruction::create_associated_token_account(
+                        &payer,
+                        &source_owner,
+                        &mint.pubkey(),
+                        &spl_token_2022::id(),
+                    ),
+                    spl_associated_token_account::instruction::create_associated_token_account(
+                        &payer,
+                        &destination_owner,
+                        &mint.pubkey(),
+                        &spl_token_2022::id(),
+                    ),
+                    spl_token_2022::instruction::mint_to_checked(
+                        &spl_token_2022::id(),
+                        &mint.pubkey(),
+                        &source,
+                        &source_owner,
+                        &[],
+                        100,
+                        DECIMALS,
+                    )
+                    .unwrap(),
+                ],
+                &[&self.source_owner],
+            )
+            .await;
+        self.mint = mint;
+        self.source = source;
+        self.destination = destination;
+    }
+
+    /// Links a secondary wallet, which has no profile or soulbound token, to
+    /// the source owner's profile, funds it with tokens, and switches the
+    /// transfers to send from it.
+    async fn switch_to_linked_source_wallet(&mut self) {
+        let mint = self.mint.pubkey();
+        let primary = self.source_owner.insecure_clone();
+        let secondary = setup_wallet(&mut self.context);
+        let secondary_token_account = get_associated_token_address_with_program_id(
+            &secondary.pubkey(),
+            &mint,
+            &spl_token_2022::id(),
+        );
+        let payer = self.context.payer.pubkey();
+        self.context
+            .expect_success(
+                &[
+                    order_tracker::instruction::link_wallet(
+                        &DEFAULT_NAMESPACE,
+                        &primary.pubkey(),
+                        &secondary.pubkey(),
+                    ),
+                    spl_associated_token_account::instruction::create_associated_token_account(
+                        &payer,
+                        &secondary.pubkey(),
+                        &mint,
+                        &spl_token_2022::id(),
+                    ),
+                    spl_token_2022::instruction::mint_to_checked(
+                        &spl_token_2022::id(),
+                        &mint,
+                        &secondary_token_account,
+                        &primary.pubkey(),
+                        &[],
+                        50,
+                        DECIMALS,
+                    )
+                    .unwrap(),
+                ],
+                &[&primary, &secondary],
+            )
+            .await;
+
+        self.source = secondary_token_account;
+        self.source_owner = secondary;
+    }
+
+    /// Requires permits for transfers above 5 tokens, returning the approver.
+    async fn require_permits(&mut self) -> Keypair {
+        let approver = setup_wallet(&mut self.context);
+        self.update_mint_rules(&MintRules {
+            permit_threshold: 5,
+            permit_approver: approver.pubkey(),
+            ..MintRules::default()
+        })
+        .await;
+        approver
+    }
+
+    async fn issue_transfer_permit(&mut self, approver: &Keypair, max_amount: u64, expiry: i64) {
+        let mint = self.mint.pubkey();
+        self.context
+            .expect_success(
+                &[order_tracker::instruction::issue_transfer_permit(
+                    &mint,
+                    &approver.pubkey(),
+                    &self.source,
+                    &self.destination,
+                    max_amount,
+                    expiry,
+                )],
+                &[approver],
+            )
+            .await;
+    }
+
+    /// Approves a delegate for the source token account.
+    async fn approve_delegate(&mut self, delegate: &Pubkey) {
+        let source = self.source;
+        let source_owner = self.source_owner.pubkey();
+        self.context
+            .expect_success(
+                &[spl_token_2022::instruction::approve(
+                    &spl_token_2022::id(),
+                    &source,
+                    delegate,
+                    &source_owner,
+                    &[],
+                    50,
+                )
+                .unwrap()],
+                &[&self.source_owner],
+            )
+            .await;
+    }
+
+    async fn update_mint_rules(&mut self, rules: &MintRules) {
+        // The source owner is the mint authority.
+        let mint = self.mint.pubkey();
+        let mint_authority = self.source_owner.pubkey();
+        self.context
+            .expect_success(
+                &[order_tracker::instruction::update_mint_rules(
+                    &mint,
+                    &mint_authority,
+                    rules,
+                )],
+                &[&self.source_owner],
+            )
+            .await;
+    }
+
+    async fn set_circuit_breaker(&mut self, threshold: u64, window_slots: u64) {
+        // The payer is the protocol admin.
+        let admin = self.context.payer.pubkey();
+        let mint = self.mint.pubkey();
+        self.context
+            .expect_success(
+                &[order_tracker::instruction::set_circuit_breaker(
+                    &DEFAULT_NAMESPACE,
+                    &admin,
+                    &mint,
+                    threshold,
+                    window_slots,
+                )],
+                &[],
+            )
+            .await;
+    }
+
+    async fn set_source_lockup(&mut self, lockup: &Lockup) {
+        // The source owner is the mint authority.
+        let mint = self.mint.pubkey();
+        let source_owner = self.source_owner.pubkey();
+        self.context
+            .expect_success(
+                &[order_tracker::instruction::set_lockup(
+                    &mint,
+                    &source_owner,
+                    &source_owner,
+                    lockup,
+                )],
+                &[&self.source_owner],
+            )
+            .await;
+    }
+
     async fn create_transfer_checked_instruction(&self, amount: u64) -> Instruction {
+        self.create_transfer_checked_instruction_with_authority(&self.source_owner.pubkey(), amount)
+            .await
+    }
+
+    async fn create_transfer_checked_instruction_with_authority(
+        &self,
+        authority: &Pubkey,
+        amount: u64,
+    ) -> Instruction {
         spl_token_2022::offchain::create_transfer_checked_instruction_with_extra_metas(
             &spl_token_2022::id(),
             &self.source,
             &self.mint.pubkey(),
             &self.destination,
-            &self.source_owner.pubkey(),
-            &[&self.source_owner.pubkey()],
+            authority,
+            &[authority],
             amount,
             DECIMALS,
             |pubkey| self.get_account_data(pubkey),
@@ -113,10 +422,12 @@ async fn setup_execute() -> ExecuteTestContext {
     let create_mint_instruction = order_tracker::instruction::create_mint(
         &mint.pubkey(),
         &source_owner.pubkey(),
+        &context.payer.pubkey(),
         DECIMALS,
         NAME,
         SYMBOL,
         URI,
+        &MintRules::default(),
     );
     let create_source_instruction =
         spl_associated_token_account::instruction::create_associated_token_account(
@@ -143,9 +454,11 @@ async fn setup_execute() -> ExecuteTestContext {
     )
     .unwrap();
     let initialize_source_profile_instruction =
-        order_tracker::instruction::initialize_profile(&source_owner.pubkey());
-    let initialize_destination_profile_instruction =
-        order_tracker::instruction::initialize_profile(&destination_owner.pubkey());
+        order_tracker::instruction::initialize_profile(&DEFAULT_NAMESPACE, &source_owner.pubkey());
+    let initialize_destination_profile_instruction = order_tracker::instruction::initialize_profile(
+        &DEFAULT_NAMESPACE,
+        &destination_owner.pubkey(),
+    );
 
     context
         .expect_success(
@@ -172,7 +485,7 @@ async fn setup_execute() -> ExecuteTestContext {
 }
 
 #[tokio::test]
-async fn fail_incorrect_soulbound_mint() {
+async fn fail_incorrect_mint_rules() {
     let context = setup_execute().await;
 
     let mut instruction = context.create_execute_instruction(10).await;
@@ -181,11 +494,7 @@ async fn fail_incorrect_soulbound_mint() {
     let ExecuteTestContext { mut context, .. } = context;
 
     context
-        .expect_error(
-            &[instruction],
-            &[],
-            (0, ProtocolError::IncorrectSoulboundMint),
-        )
+        .expect_error(&[instruction], &[], (0, ProtocolError::IncorrectMintRules))
         .await;
 }
 
@@ -198,9 +507,10 @@ async fn fail_incorrect_source_soulbound_token_account() {
     let ExecuteTestContext { mut context, .. } = context;
 
     let fake_address = Pubkey::new_unique();
-    let fake_soulbound_token_account_address = Soulbound::token_account(&fake_address);
+    let fake_soulbound_token_account_address =
+        Soulbound::token_account(&DEFAULT_NAMESPACE, &fake_address);
     setup_soulbound_token_account(&mut context, &fake_address, 1);
-    instruction.accounts[8].pubkey = fake_soulbound_token_account_address;
+    instruction.accounts[9].pubkey = fake_soulbound_token_account_address;
 
     context
         .expect_error(
@@ -243,9 +553,10 @@ async fn fail_incorrect_destination_soulbound_token_account() {
     let ExecuteTestContext { mut context, .. } = context;
 
     let fake_address = Pubkey::new_unique();
-    let fake_soulbound_token_account_address = Soulbound::token_account(&fake_address);
+    let fake_soulbound_token_account_address =
+        Soulbound::token_account(&DEFAULT_NAMESPACE, &fake_address);
     setup_soulbound_token_account(&mut context, &fake_address, 1);
-    instruction.accounts[10].pubkey = fake_soulbound_token_account_address;
+    instruction.accounts[12].pubkey = fake_soulbound_token_account_address;
 
     context
         .expect_error(
@@ -292,7 +603,7 @@ async fn fail_source_profile_does_not_exist() {
     } = context;
 
     context.set_account(
-        &Profile::address(&source_owner.pubkey()),
+        &Profile::address(&DEFAULT_NAMESPACE, &source_owner.pubkey()),
         &AccountSharedData::default(),
     );
 
@@ -318,7 +629,7 @@ async fn fail_destination_profile_does_not_exist() {
     } = context;
 
     context.set_account(
-        &Profile::address(&destination_owner.pubkey()),
+        &Profile::address(&DEFAULT_NAMESPACE, &destination_owner.pubkey()),
         &AccountSharedData::default(),
     );
 
@@ -332,26 +643,195 @@ async fn fail_destination_profile_does_not_exist() {
 }
 
 #[tokio::test]
-async fn fail_cannot_invoke_directly() {
+async fn fail_source_profile_frozen() {
     let context = setup_execute().await;
 
     let instruction = context.create_execute_instruction(10).await;
 
-    let ExecuteTestContext { mut context, .. } = context;
+    let ExecuteTestContext {
+        mut context,
+        source_owner,
+        ..
+    } = context;
+
+    let admin = context.payer.pubkey();
+    context
+        .expect_success(
+            &[order_tracker::instruction::freeze_profile(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &source_owner.pubkey(),
+            )],
+            &[],
+        )
+        .await;
+
+    context
+        .expect_error(&[instruction], &[], (0, ProtocolError::SourceProfileFrozen))
+        .await;
+}
+
+#[tokio::test]
+async fn fail_destination_profile_frozen() {
+    let context = setup_execute().await;
+
+    let instruction = context.create_execute_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        destination_owner,
+        ..
+    } = context;
+
+    let admin = context.payer.pubkey();
+    context
+        .expect_success(
+            &[order_tracker::instruction::freeze_profile(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &destination_owner.pubkey(),
+            )],
+            &[],
+        )
+        .await;
 
     context
         .expect_error(
             &[instruction],
             &[],
-            (0, TransferHookError::ProgramCalledOutsideOfTransfer),
+            (0, ProtocolError::DestinationProfileFrozen),
         )
         .await;
 }
 
 #[tokio::test]
-async fn success() {
+async fn fail_source_owner_denylisted() {
+    let context = setup_execute().await;
+
+    let instruction = context.create_execute_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner,
+        ..
+    } = context;
+
+    let admin = context.payer.pubkey();
+    context
+        .expect_success(
+            &[order_tracker::instruction::add_to_denylist(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &source_owner.pubkey(),
+            )],
+            &[],
+        )
+        .await;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[],
+            (0, ProtocolError::SourceOwnerDenylisted),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_destination_owner_denylisted() {
+    let context = setup_execute().await;
+
+    let instruction = context.create_execute_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        destination_owner,
+        ..
+    } = context;
+
+    let admin = context.payer.pubkey();
+    context
+        .expect_success(
+            &[order_tracker::instruction::add_to_denylist(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &destination_owner.pubkey(),
+            )],
+            &[],
+        )
+        .await;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[],
+            (0, ProtocolError::DestinationOwnerDenylisted),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_destination_owner_denylisted_among_others() {
     let context = setup_execute().await;
 
+    let first = context.create_transfer_checked_instruction(10).await;
+    let second = context.create_transfer_checked_instruction(11).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner,
+        destination_owner,
+        ..
+    } = context;
+
+    let admin = context.payer.pubkey();
+    for _ in 0..8 {
+        context
+            .expect_success(
+                &[order_tracker::instruction::add_to_denylist(
+                    &DEFAULT_NAMESPACE,
+                    &admin,
+                    &Pubkey::new_unique(),
+                )],
+                &[],
+            )
+            .await;
+    }
+
+    // Other denylisted wallets don't bar the transfer.
+    context.expect_success(&[first], &[&source_owner]).await;
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::add_to_denylist(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &destination_owner.pubkey(),
+            )],
+            &[],
+        )
+        .await;
+
+    context
+        .expect_error(
+            &[second],
+            &[&source_owner],
+            (0, ProtocolError::DestinationOwnerDenylisted),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_transfer_below_minimum() {
+    let mut context = setup_execute().await;
+
+    context
+        .update_mint_rules(&MintRules {
+            min_amount: 11,
+            ..MintRules::default()
+        })
+        .await;
+
     let instruction = context.create_transfer_checked_instruction(10).await;
 
     let ExecuteTestContext {
@@ -360,5 +840,2028 @@ async fn success() {
         ..
     } = context;
 
-    context.expect_success(&[instruction], &[&wallet]).await;
+    context
+        .expect_error(
+            &[instruction],
+            &[&wallet],
+            (0, ProtocolError::TransferBelowMinimum),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_transfer_above_maximum() {
+    let mut context = setup_execute().await;
+
+    context
+        .update_mint_rules(&MintRules {
+            max_amount: 9,
+            ..MintRules::default()
+        })
+        .await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&wallet],
+            (0, ProtocolError::TransferAboveMaximum),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_outflow_limit_exceeded() {
+    let mut context = setup_execute().await;
+    context.set_outflow_limit(15).await;
+
+    let first = context.create_transfer_checked_instruction(10).await;
+    let second = context.create_transfer_checked_instruction(6).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context
+        .expect_error(
+            &[first, second],
+            &[&wallet],
+            (1, ProtocolError::OutflowLimitExceeded),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_outflow_window_elapsed() {
+    let mut context = setup_execute().await;
+    context.set_outflow_limit(15).await;
+
+    let first = context.create_transfer_checked_instruction(10).await;
+    let second = context.create_transfer_checked_instruction(6).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        mint,
+        ..
+    } = context;
+
+    context.expect_success(&[first], &[&wallet]).await;
+
+    // Once the outflow window has elapsed, the earlier transfer no longer
+    // counts against the limit.
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp += MintActivity::OUTFLOW_WINDOW + HOUR;
+    context.set_sysvar(&clock);
+
+    context.expect_success(&[second], &[&wallet]).await;
+
+    let account = context
+        .banks_client
+        .get_account(MintActivity::address(&wallet.pubkey(), &mint.pubkey()))
+        .await
+        .unwrap()
+        .unwrap();
+    let activity = MintActivity::try_from_slice(&account.data).unwrap();
+    assert_eq!(activity.outflow_limit, 15);
+    assert_eq!(
+        activity
+            .outflow
+            .volume_at(clock.unix_timestamp, MintActivity::OUTFLOW_WINDOW),
+        6
+    );
+    assert_eq!(
+        get_profile(&mut context, &wallet.pubkey())
+            .await
+            .order_volume,
+        16
+    );
+}
+
+#[tokio::test]
+async fn fail_outflow_limit_exceeded_across_window_start() {
+    let mut context = setup_execute().await;
+    context.set_outflow_limit(15).await;
+
+    let first = context.create_transfer_checked_instruction(10).await;
+    let second = context.create_transfer_checked_instruction(5).await;
+    let third = context.create_transfer_checked_instruction(11).await;
+    let fourth = context.create_transfer_checked_instruction(9).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    warp_to_timestamp(&mut context, MONDAY).await;
+    context.expect_success(&[first], &[&wallet]).await;
+
+    warp_to_timestamp(&mut context, MONDAY + 23 * HOUR).await;
+    context.expect_success(&[second], &[&wallet]).await;
+
+    // A day after the first transfer only it has left the window, so the
+    // second transfer still counts against the limit.
+    warp_to_timestamp(&mut context, MONDAY + 25 * HOUR).await;
+    context
+        .expect_error(
+            &[third],
+            &[&wallet],
+            (0, ProtocolError::OutflowLimitExceeded),
+        )
+        .await;
+    context.expect_success(&[fourth], &[&wallet]).await;
+}
+
+#[tokio::test]
+async fn fail_outflow_limit_shared_with_linked_wallet() {
+    let mut context = setup_execute().await;
+    context.set_outflow_limit(15).await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+    context
+        .context
+        .expect_success(&[instruction], &[&context.source_owner])
+        .await;
+
+    // A wallet linked to the source owner's profile sends under the same
+    // outflow limit.
+    context.switch_to_linked_source_wallet().await;
+    let instruction = context.create_transfer_checked_instruction(6).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&wallet],
+            (0, ProtocolError::OutflowLimitExceeded),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_outflow_limit_for_linked_wallet() {
+    let mut context = setup_execute().await;
+    context.switch_to_linked_source_wallet().await;
+
+    // Outflow limits are set on the profile's primary wallet.
+    let admin = context.context.payer.pubkey();
+    let mint = context.mint.pubkey();
+    let secondary = context.source_owner.pubkey();
+    context
+        .context
+        .expect_error(
+            &[order_tracker::instruction::set_outflow_limit(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &mint,
+                &secondary,
+                15,
+            )],
+            &[],
+            (0, ProtocolError::ProfileNotInitialized),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_destination_above_maximum_balance() {
+    let mut context = setup_execute().await;
+
+    context
+        .update_mint_rules(&MintRules {
+            max_balance: 9,
+            ..MintRules::default()
+        })
+        .await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&wallet],
+            (0, ProtocolError::DestinationAboveMaximumBalance),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_exempt_destination_above_maximum_balance() {
+    let mut context = setup_execute().await;
+
+    context
+        .update_mint_rules(&MintRules {
+            max_balance: 9,
+            ..MintRules::default()
+        })
+        .await;
+
+    // The mint authority exempts the destination owner, such as a treasury, from
+    // the maximum balance.
+    let mint = context.mint.pubkey();
+    let mint_authority = context.source_owner.pubkey();
+    let destination_owner = context.destination_owner.pubkey();
+    context
+        .context
+        .expect_success(
+            &[order_tracker::instruction::add_balance_cap_exemption(
+                &mint,
+                &mint_authority,
+                &destination_owner,
+            )],
+            &[&context.source_owner],
+        )
+        .await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context.expect_success(&[instruction], &[&wallet]).await;
+}
+
+#[tokio::test]
+async fn fail_credential_exempt_destination_above_maximum_balance() {
+    let mut context = setup_execute().await;
+
+    context
+        .update_mint_rules(&MintRules {
+            max_balance: 9,
+            ..MintRules::default()
+        })
+        .await;
+
+    // Exempting the destination owner from the soulbound and profile
+    // requirements does not exempt it from the maximum balance.
+    let mint = context.mint.pubkey();
+    let mint_authority = context.source_owner.pubkey();
+    let destination_owner = context.destination_owner.pubkey();
+    context
+        .context
+        .expect_success(
+            &[order_tracker::instruction::add_exemption(
+                &mint,
+                &mint_authority,
+                &destination_owner,
+            )],
+            &[&context.source_owner],
+        )
+        .await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&wallet],
+            (0, ProtocolError::DestinationAboveMaximumBalance),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_transfer_exceeds_unlocked_balance() {
+    let mut context = setup_execute().await;
+
+    // The source holds 100 tokens, of which 95 are locked until the cliff.
+    let clock = context
+        .context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap();
+    context
+        .set_source_lockup(&Lockup {
+            locked_amount: 95,
+            unlock_start: clock.unix_timestamp + 1_000,
+            unlock_end: clock.unix_timestamp + 2_000,
+        })
+        .await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&wallet],
+            (0, ProtocolError::TransferExceedsUnlockedBalance),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_locked_source_not_associated_token_account() {
+    let mut context = setup_execute().await;
+
+    // The source owner holds tokens in a second token account, besides its
+    // associated token account holding the locked tokens.
+    let other_source = Keypair::new();
+    let mint = context.mint.pubkey();
+    let source_owner = context.source_owner.pubkey();
+    let payer = context.context.payer.pubkey();
+    let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(&[
+        ExtensionType::TransferHookAccount,
+    ])
+    .unwrap();
+    let rent = context
+        .context
+        .banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(space);
+    context
+        .context
+        .expect_success(
+            &[
+                system_instruction::create_account(
+                    &payer,
+                    &other_source.pubkey(),
+                    rent,
+                    space as u64,
+                    &spl_token_2022::id(),
+                ),
+                spl_token_2022::instruction::initialize_account3(
+                    &spl_token_2022::id(),
+                    &other_source.pubkey(),
+                    &mint,
+                    &source_owner,
+                )
+                .unwrap(),
+                spl_token_2022::instruction::mint_to_checked(
+                    &spl_token_2022::id(),
+                    &mint,
+                    &other_source.pubkey(),
+                    &source_owner,
+                    &[&source_owner],
+                    100,
+                    DECIMALS,
+                )
+                .unwrap(),
+            ],
+            &[&other_source, &context.source_owner],
+        )
+        .await;
+
+    let clock = context
+        .context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap();
+    context
+        .set_source_lockup(&Lockup {
+            locked_amount: 95,
+            unlock_start: clock.unix_timestamp + 1_000,
+            unlock_end: clock.unix_timestamp + 2_000,
+        })
+        .await;
+
+    context.source = other_source.pubkey();
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&wallet],
+            (0, ProtocolError::LockedSourceNotAssociatedTokenAccount),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_transfer_unlocked_balance() {
+    let mut context = setup_execute().await;
+
+    let mut clock = context
+        .context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap();
+    context
+        .set_source_lockup(&Lockup {
+            locked_amount: 95,
+            unlock_start: clock.unix_timestamp + 1_000,
+            unlock_end: clock.unix_timestamp + 2_000,
+        })
+        .await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    // Halfway through the unlock, fewer than half of the tokens are locked.
+    clock.unix_timestamp += 1_500;
+    context.set_sysvar(&clock);
+
+    context.expect_success(&[instruction], &[&wallet]).await;
+}
+
+#[tokio::test]
+async fn fail_mint_activity_not_initialized() {
+    let mut context = setup_execute().await;
+
+    context
+        .update_mint_rules(&MintRules {
+            transfer_cooldown_slots: 10,
+            ..MintRules::default()
+        })
+        .await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&wallet],
+            (0, ProtocolError::MintActivityNotInitialized),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_transfer_cooldown_active() {
+    let mut context = setup_execute().await;
+
+    context
+        .update_mint_rules(&MintRules {
+            transfer_cooldown_slots: 10,
+            ..MintRules::default()
+        })
+        .await;
+    context.initialize_mint_activity().await;
+
+    let first = context.create_transfer_checked_instruction(10).await;
+    let second = context.create_transfer_checked_instruction(6).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context
+        .expect_error(
+            &[first, second],
+            &[&wallet],
+            (1, ProtocolError::TransferCooldownActive),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_transfer_cooldown_per_mint() {
+    let mut context = setup_execute().await;
+
+    context
+        .update_mint_rules(&MintRules {
+            transfer_cooldown_slots: 10,
+            ..MintRules::default()
+        })
+        .await;
+    context.initialize_mint_activity().await;
+    let first = context.create_transfer_checked_instruction(10).await;
+
+    // A transfer of another mint, in the same slot, is not held back by the
+    // first mint's cooldown.
+    context.switch_to_other_mint().await;
+    context
+        .update_mint_rules(&MintRules {
+            transfer_cooldown_slots: 10,
+            ..MintRules::default()
+        })
+        .await;
+    context.initialize_mint_activity().await;
+    let second = context.create_transfer_checked_instruction(6).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context.expect_success(&[first, second], &[&wallet]).await;
+}
+
+#[tokio::test]
+async fn success_transfer_cooldown_elapsed() {
+    let mut context = setup_execute().await;
+
+    context
+        .update_mint_rules(&MintRules {
+            transfer_cooldown_slots: 10,
+            ..MintRules::default()
+        })
+        .await;
+    context.initialize_mint_activity().await;
+
+    let first = context.create_transfer_checked_instruction(10).await;
+    let second = context.create_transfer_checked_instruction(6).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context.expect_success(&[first], &[&wallet]).await;
+
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.slot += 10;
+    context.set_sysvar(&clock);
+
+    context.expect_success(&[second], &[&wallet]).await;
+}
+
+// Monday, January 1, 2024 00:00:00 UTC.
+const MONDAY: i64 = 1_704_067_200;
+const MINUTE: i64 = 60;
+const HOUR: i64 = 60 * MINUTE;
+
+async fn warp_to_timestamp(context: &mut ProgramTestContext, unix_timestamp: i64) {
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = unix_timestamp;
+    context.set_sysvar(&clock);
+}
+
+impl ExecuteTestContext {
+    /// Restricts trading to Mondays, 09:00 to 17:00 UTC.
+    async fn set_market_hours(&mut self) {
+        // The source owner is the mint authority.
+        let mint = self.mint.pubkey();
+        let mint_authority = self.source_owner.pubkey();
+        self.context
+            .expect_success(
+                &[order_tracker::instruction::set_trading_windows(
+                    &mint,
+                    &mint_authority,
+                    &[TradingWindow {
+                        days: 1 << 1,
+                        open_second: 9 * HOUR as u32,
+                        close_second: 17 * HOUR as u32,
+                    }],
+                )],
+                &[&self.source_owner],
+            )
+            .await;
+    }
+
+    async fn set_trading_override(&mut self, trading_override: TradingOverride) {
+        let admin = self.context.payer.pubkey();
+        let mint = self.mint.pubkey();
+        self.context
+            .expect_success(
+                &[order_tracker::instruction::set_trading_override(
+                    &DEFAULT_NAMESPACE,
+                    &admin,
+                    &mint,
+                    trading_override,
+                )],
+                &[],
+            )
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn fail_trading_closed_outside_window() {
+    let mut context = setup_execute().await;
+
+    context.set_market_hours().await;
+    warp_to_timestamp(&mut context.context, MONDAY + 8 * HOUR).await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&wallet],
+            (0, ProtocolError::TradingClosed),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_trading_closed_on_other_day() {
+    let mut context = setup_execute().await;
+
+    context.set_market_hours().await;
+    warp_to_timestamp(&mut context.context, MONDAY + 24 * HOUR + 10 * HOUR).await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&wallet],
+            (0, ProtocolError::TradingClosed),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_trading_inside_window() {
+    let mut context = setup_execute().await;
+
+    context.set_market_hours().await;
+    warp_to_timestamp(&mut context.context, MONDAY + 10 * HOUR).await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context.expect_success(&[instruction], &[&wallet]).await;
+}
+
+#[tokio::test]
+async fn fail_trading_override_closed() {
+    let mut context = setup_execute().await;
+
+    context.set_market_hours().await;
+    context.set_trading_override(TradingOverride::Closed).await;
+    warp_to_timestamp(&mut context.context, MONDAY + 10 * HOUR).await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&wallet],
+            (0, ProtocolError::TradingClosed),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_trading_override_open() {
+    let mut context = setup_execute().await;
+
+    context.set_market_hours().await;
+    context.set_trading_override(TradingOverride::Open).await;
+    warp_to_timestamp(&mut context.context, MONDAY + 8 * HOUR).await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context.expect_success(&[instruction], &[&wallet]).await;
+}
+
+#[tokio::test]
+async fn fail_delegate_without_credential() {
+    let mut context = setup_execute().await;
+
+    // The delegate has no soulbound token or profile.
+    let delegate = setup_wallet(&mut context.context);
+    context.approve_delegate(&delegate.pubkey()).await;
+
+    let instruction = context
+        .create_transfer_checked_instruction_with_authority(&delegate.pubkey(), 10)
+        .await;
+
+    let ExecuteTestContext { mut context, .. } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&delegate],
+            (0, ProtocolError::SoulboundTokenAccountIsEmpty),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_delegate_without_credential_not_required() {
+    let mut context = setup_execute().await;
+
+    context
+        .update_mint_rules(&MintRules {
+            require_delegate_credential: false,
+            ..MintRules::default()
+        })
+        .await;
+    let delegate = setup_wallet(&mut context.context);
+    context.approve_delegate(&delegate.pubkey()).await;
+
+    let instruction = context
+        .create_transfer_checked_instruction_with_authority(&delegate.pubkey(), 10)
+        .await;
+
+    let ExecuteTestContext { mut context, .. } = context;
+
+    context.expect_success(&[instruction], &[&delegate]).await;
+}
+
+#[tokio::test]
+async fn fail_delegate_denylisted_credential_not_required() {
+    let mut context = setup_execute().await;
+
+    // The denylist applies to delegates even when the mint doesn't require a
+    // credential from them.
+    context
+        .update_mint_rules(&MintRules {
+            require_delegate_credential: false,
+            ..MintRules::default()
+        })
+        .await;
+    let delegate = setup_wallet(&mut context.context);
+    context.approve_delegate(&delegate.pubkey()).await;
+    let admin = context.context.payer.pubkey();
+    context
+        .context
+        .expect_success(
+            &[order_tracker::instruction::add_to_denylist(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &delegate.pubkey(),
+            )],
+            &[],
+        )
+        .await;
+
+    let instruction = context
+        .create_transfer_checked_instruction_with_authority(&delegate.pubkey(), 10)
+        .await;
+
+    let ExecuteTestContext { mut context, .. } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&delegate],
+            (0, ProtocolError::DelegateDenylisted),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_delegate_profile_frozen() {
+    let mut context = setup_execute().await;
+
+    let delegate = setup_profile(&mut context.context).await;
+    context.approve_delegate(&delegate.pubkey()).await;
+    let admin = context.context.payer.pubkey();
+    context
+        .context
+        .expect_success(
+            &[order_tracker::instruction::freeze_profile(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &delegate.pubkey(),
+            )],
+            &[],
+        )
+        .await;
+
+    let instruction = context
+        .create_transfer_checked_instruction_with_authority(&delegate.pubkey(), 10)
+        .await;
+
+    let ExecuteTestContext { mut context, .. } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&delegate],
+            (0, ProtocolError::DelegateProfileFrozen),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_delegate_volume_recorded() {
+    let mut context = setup_execute().await;
+
+    let delegate = setup_wallet_with_soulbound_token_account(&mut context.context);
+    context
+        .context
+        .expect_success(
+            &[order_tracker::instruction::initialize_profile(
+                &DEFAULT_NAMESPACE,
+                &delegate.pubkey(),
+            )],
+            &[&delegate],
+        )
+        .await;
+    context.approve_delegate(&delegate.pubkey()).await;
+
+    let instruction = context
+        .create_transfer_checked_instruction_with_authority(&delegate.pubkey(), 10)
+        .await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner,
+        ..
+    } = context;
+
+    context.expect_success(&[instruction], &[&delegate]).await;
+
+    // The owner's volume includes the transfer, while the delegate's volume is
+    // recorded separately.
+    let account = context
+        .banks_client
+        .get_account(Profile::address(&DEFAULT_NAMESPACE, &source_owner.pubkey()))
+        .await
+        .unwrap()
+        .unwrap();
+    let profile = Profile::try_from_slice(&account.data).unwrap();
+    assert_eq!(profile.order_volume, 10);
+    assert_eq!(profile.delegated_volume, 0);
+
+    let account = context
+        .banks_client
+        .get_account(Profile::address(&DEFAULT_NAMESPACE, &delegate.pubkey()))
+        .await
+        .unwrap()
+        .unwrap();
+    let profile = Profile::try_from_slice(&account.data).unwrap();
+    assert_eq!(profile.order_volume, 0);
+    assert_eq!(profile.delegated_volume, 10);
+}
+
+#[tokio::test]
+async fn fail_cannot_invoke_directly() {
+    let context = setup_execute().await;
+
+    let instruction = context.create_execute_instruction(10).await;
+
+    let ExecuteTestContext { mut context, .. } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[],
+            (0, TransferHookError::ProgramCalledOutsideOfTransfer),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success() {
+    let context = setup_execute().await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context.expect_success(&[instruction], &[&wallet]).await;
+}
+
+#[tokio::test]
+async fn success_transfer_recorded_event() {
+    let context = setup_execute().await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        mint,
+        source,
+        destination,
+        source_owner,
+        destination_owner,
+    } = context;
+
+    let events = context
+        .expect_events(&[instruction], &[&source_owner])
+        .await;
+    assert_eq!(
+        events,
+        vec![ProtocolEvent::TransferRecorded {
+            mint: mint.pubkey(),
+            source,
+            destination,
+            source_owner: source_owner.pubkey(),
+            destination_owner: destination_owner.pubkey(),
+            amount: 10,
+            new_totals: VolumeTotals {
+                mint_volume: 10,
+                order_volume: 10,
+                self_transfer_volume: 0,
+            },
+        }]
+    );
+}
+
+#[tokio::test]
+async fn success_source_owner_exempt() {
+    let mut context = setup_execute().await;
+
+    // The mint authority exempts the source owner, who has no profile or
+    // soulbound token.
+    let mint = context.mint.pubkey();
+    let source_owner = context.source_owner.pubkey();
+    context
+        .context
+        .expect_success(
+            &[order_tracker::instruction::add_exemption(
+                &mint,
+                &source_owner,
+                &source_owner,
+            )],
+            &[&context.source_owner],
+        )
+        .await;
+    context.context.set_account(
+        &Profile::address(&DEFAULT_NAMESPACE, &source_owner),
+        &AccountSharedData::default(),
+    );
+    setup_soulbound_token_account(&mut context.context, &source_owner, 0);
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        destination_owner,
+        ..
+    } = context;
+
+    context.expect_success(&[instruction], &[&wallet]).await;
+
+    // The volume is recorded for the non-exempt destination owner.
+    let account = context
+        .banks_client
+        .get_account(Profile::address(
+            &DEFAULT_NAMESPACE,
+            &destination_owner.pubkey(),
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    let profile = Profile::try_from_slice(&account.data).unwrap();
+    assert_eq!(profile.order_volume, 10);
+}
+
+#[tokio::test]
+async fn success_linked_wallet() {
+    let mut context = setup_execute().await;
+    let primary = context.source_owner.pubkey();
+    context.switch_to_linked_source_wallet().await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context.expect_success(&[instruction], &[&wallet]).await;
+
+    // The secondary wallet trades under the primary wallet's credential, and
+    // the volume is recorded on the primary wallet's profile.
+    assert_eq!(get_profile(&mut context, &primary).await.order_volume, 10);
+    assert!(context
+        .banks_client
+        .get_account(Profile::address(&DEFAULT_NAMESPACE, &wallet.pubkey()))
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn fail_linked_wallet_primary_frozen() {
+    let mut context = setup_execute().await;
+    let primary = context.source_owner.pubkey();
+    context.switch_to_linked_source_wallet().await;
+
+    // Freezing the primary wallet's profile also stops the secondary wallet.
+    let admin = context.context.payer.pubkey();
+    context
+        .context
+        .expect_success(
+            &[order_tracker::instruction::freeze_profile(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &primary,
+            )],
+            &[],
+        )
+        .await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&wallet],
+            (0, ProtocolError::SourceProfileFrozen),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_linked_wallet_primary_without_credential() {
+    let mut context = setup_execute().await;
+    let primary = context.source_owner.pubkey();
+    context.switch_to_linked_source_wallet().await;
+
+    // The primary wallet's soulbound token is gone, so the secondary wallet
+    // has no credential to trade under.
+    setup_soulbound_token_account(&mut context.context, &primary, 0);
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&wallet],
+            (0, ProtocolError::SoulboundTokenAccountIsEmpty),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_destination_credential_not_required() {
+    let mut context = setup_execute().await;
+
+    // The mint authority stops requiring a credential from the destination and
+    // stops tracking volume. The destination owner has no profile.
+    let destination_owner = context.destination_owner.pubkey();
+    context
+        .update_mint_rules(&MintRules {
+            require_destination_credential: false,
+            track_volume: false,
+            ..MintRules::default()
+        })
+        .await;
+    context.context.set_account(
+        &Profile::address(&DEFAULT_NAMESPACE, &destination_owner),
+        &AccountSharedData::default(),
+    );
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context.expect_success(&[instruction], &[&wallet]).await;
+
+    // No volume is recorded.
+    let account = context
+        .banks_client
+        .get_account(Profile::address(&DEFAULT_NAMESPACE, &wallet.pubkey()))
+        .await
+        .unwrap()
+        .unwrap();
+    let profile = Profile::try_from_slice(&account.data).unwrap();
+    assert_eq!(profile.order_volume, 0);
+}
+
+#[tokio::test]
+async fn success_self_linked_destination_credential_not_required() {
+    let mut context = setup_execute().await;
+
+    // The destination is a wallet that never had a profile, which anyone may
+    // link to itself so it can receive the mint.
+    context
+        .update_mint_rules(&MintRules {
+            require_destination_credential: false,
+            ..MintRules::default()
+        })
+        .await;
+    let mint = context.mint.pubkey();
+    let destination_owner = setup_wallet(&mut context.context);
+    let destination = get_associated_token_address_with_program_id(
+        &destination_owner.pubkey(),
+        &mint,
+        &spl_token_2022::id(),
+    );
+    let payer = context.context.payer.pubkey();
+    context
+        .context
+        .expect_success(
+            &[
+                order_tracker::instruction::initialize_wallet_link(
+                    &DEFAULT_NAMESPACE,
+                    &payer,
+                    &destination_owner.pubkey(),
+                ),
+                spl_associated_token_account::instruction::create_associated_token_account(
+                    &payer,
+                    &destination_owner.pubkey(),
+                    &mint,
+                    &spl_token_2022::id(),
+                ),
+            ],
+            &[],
+        )
+        .await;
+    context.destination = destination;
+    context.destination_owner = destination_owner;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context.expect_success(&[instruction], &[&wallet]).await;
+
+    let account = context
+        .banks_client
+        .get_account(Profile::address(&DEFAULT_NAMESPACE, &wallet.pubkey()))
+        .await
+        .unwrap()
+        .unwrap();
+    let profile = Profile::try_from_slice(&account.data).unwrap();
+    assert_eq!(profile.order_volume, 10);
+}
+
+#[tokio::test]
+async fn success_same_profile_transfer_excluded_from_volume() {
+    let mut context = setup_execute().await;
+
+    // Link a secondary wallet to the source owner's profile, and send tokens
+    // to it.
+    let mint = context.mint.pubkey();
+    let primary = context.source_owner.insecure_clone();
+    let secondary = setup_wallet(&mut context.context);
+    let secondary_token_account = get_associated_token_address_with_program_id(
+        &secondary.pubkey(),
+        &mint,
+        &spl_token_2022::id(),
+    );
+    let payer = context.context.payer.pubkey();
+    context
+        .context
+        .expect_success(
+            &[
+                order_tracker::instruction::link_wallet(
+                    &DEFAULT_NAMESPACE,
+                    &primary.pubkey(),
+                    &secondary.pubkey(),
+                ),
+                spl_associated_token_account::instruction::create_associated_token_account(
+                    &payer,
+                    &secondary.pubkey(),
+                    &mint,
+                    &spl_token_2022::id(),
+                ),
+            ],
+            &[&primary, &secondary],
+        )
+        .await;
+
+    context.destination = secondary_token_account;
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context.expect_success(&[instruction], &[&wallet]).await;
+
+    let account = context
+        .banks_client
+        .get_account(Profile::address(&DEFAULT_NAMESPACE, &primary.pubkey()))
+        .await
+        .unwrap()
+        .unwrap();
+    let profile = Profile::try_from_slice(&account.data).unwrap();
+    assert_eq!(profile.order_volume, 0);
+    assert_eq!(profile.self_transfer_volume, 10);
+}
+
+#[tokio::test]
+async fn fail_pair_volume_not_initialized() {
+    let mut context = setup_execute().await;
+
+    // With wash trading detection enabled, every pair of profiles is tracked,
+    // so their pair volume accounts must be initialized.
+    context.enable_wash_trading_detection(false).await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+    context
+        .context
+        .expect_error(
+            &[instruction],
+            &[&context.source_owner],
+            (0, ProtocolError::PairVolumeNotInitialized),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_round_trip_flags_profiles() {
+    let mut context = setup_execute().await;
+
+    context.enable_wash_trading_detection(false).await;
+    context.initialize_pair_volume().await;
+
+    let outgoing = context.create_transfer_checked_instruction(10).await;
+    context
+        .context
+        .expect_success(&[outgoing], &[&context.source_owner])
+        .await;
+    let source_owner = context.source_owner.pubkey();
+    let destination_owner = context.destination_owner.pubkey();
+    assert!(
+        !get_profile(&mut context.context, &source_owner)
+            .await
+            .wash_trading_flagged
+    );
+
+    // More than half of the volume comes back.
+    let returning = context.create_return_transfer_checked_instruction(6).await;
+    context
+        .context
+        .expect_success(&[returning], &[&context.destination_owner])
+        .await;
+    assert!(
+        get_profile(&mut context.context, &source_owner)
+            .await
+            .wash_trading_flagged
+    );
+    assert!(
+        get_profile(&mut context.context, &destination_owner)
+            .await
+            .wash_trading_flagged
+    );
+}
+
+#[tokio::test]
+async fn success_round_trip_through_linked_wallet_flags_profiles() {
+    let mut context = setup_execute().await;
+
+    context.enable_wash_trading_detection(false).await;
+    context.initialize_pair_volume().await;
+
+    let outgoing = context.create_transfer_checked_instruction(10).await;
+    context
+        .context
+        .expect_success(&[outgoing], &[&context.source_owner])
+        .await;
+
+    // The volume comes back to another wallet linked to the source owner's
+    // profile, which is tracked under the same pair.
+    let source_owner = context.source_owner.pubkey();
+    let destination_owner = context.destination_owner.pubkey();
+    context.switch_to_linked_source_wallet().await;
+    let returning = context.create_return_transfer_checked_instruction(6).await;
+    context
+        .context
+        .expect_success(&[returning], &[&context.destination_owner])
+        .await;
+
+    assert!(
+        get_profile(&mut context.context, &source_owner)
+            .await
+            .wash_trading_flagged
+    );
+    assert!(
+        get_profile(&mut context.context, &destination_owner)
+            .await
+            .wash_trading_flagged
+    );
+}
+
+#[tokio::test]
+async fn success_round_trip_across_window_start_flags_profiles() {
+    let mut context = setup_execute().await;
+
+    context.enable_wash_trading_detection(false).await;
+    context.initialize_pair_volume().await;
+
+    // Most of the volume sent late in the hour after the first transfer comes
+    // back after that hour has passed, but within an hour of being sent.
+    warp_to_timestamp(&mut context.context, MONDAY).await;
+    let first = context.create_transfer_checked_instruction(1).await;
+    context
+        .context
+        .expect_success(&[first], &[&context.source_owner])
+        .await;
+    warp_to_timestamp(&mut context.context, MONDAY + 50 * MINUTE).await;
+    let second = context.create_transfer_checked_instruction(10).await;
+    context
+        .context
+        .expect_success(&[second], &[&context.source_owner])
+        .await;
+    warp_to_timestamp(&mut context.context, MONDAY + 70 * MINUTE).await;
+    let returning = context.create_return_transfer_checked_instruction(6).await;
+    context
+        .context
+        .expect_success(&[returning], &[&context.destination_owner])
+        .await;
+
+    let source_owner = context.source_owner.pubkey();
+    assert!(
+        get_profile(&mut context.context, &source_owner)
+            .await
+            .wash_trading_flagged
+    );
+}
+
+#[tokio::test]
+async fn fail_wash_trading_flagged() {
+    let mut context = setup_execute().await;
+
+    context.enable_wash_trading_detection(true).await;
+    context.initialize_pair_volume().await;
+
+    let outgoing = context.create_transfer_checked_instruction(10).await;
+    context
+        .context
+        .expect_success(&[outgoing], &[&context.source_owner])
+        .await;
+    let returning = context.create_return_transfer_checked_instruction(6).await;
+    context
+        .context
+        .expect_success(&[returning], &[&context.destination_owner])
+        .await;
+
+    let instruction = context.create_transfer_checked_instruction(7).await;
+    context
+        .context
+        .expect_error(
+            &[instruction],
+            &[&context.source_owner],
+            (0, ProtocolError::WashTradingFlagged),
+        )
+        .await;
+
+    // Once the protocol admin clears both flags, trading resumes.
+    let admin = context.context.payer.pubkey();
+    let source_owner = context.source_owner.pubkey();
+    let destination_owner = context.destination_owner.pubkey();
+    context
+        .context
+        .expect_success(
+            &[
+                order_tracker::instruction::clear_wash_trading_flag(
+                    &DEFAULT_NAMESPACE,
+                    &admin,
+                    &source_owner,
+                ),
+                order_tracker::instruction::clear_wash_trading_flag(
+                    &DEFAULT_NAMESPACE,
+                    &admin,
+                    &destination_owner,
+                ),
+            ],
+            &[],
+        )
+        .await;
+    let instruction = context.create_transfer_checked_instruction(8).await;
+    context
+        .context
+        .expect_success(&[instruction], &[&context.source_owner])
+        .await;
+}
+
+#[tokio::test]
+async fn fail_transfer_permit_required() {
+    let mut context = setup_execute().await;
+
+    context.require_permits().await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&wallet],
+            (0, ProtocolError::TransferPermitRequired),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_transfer_permit_exceeded() {
+    let mut context = setup_execute().await;
+
+    let approver = context.require_permits().await;
+    context.issue_transfer_permit(&approver, 9, i64::MAX).await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&wallet],
+            (0, ProtocolError::TransferPermitExceeded),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_transfer_permit_expired() {
+    let mut context = setup_execute().await;
+
+    let approver = context.require_permits().await;
+    context.issue_transfer_permit(&approver, 10, MONDAY).await;
+    warp_to_timestamp(&mut context.context, MONDAY + 1).await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&wallet],
+            (0, ProtocolError::TransferPermitExpired),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_transfer_permit_consumed() {
+    let mut context = setup_execute().await;
+
+    let approver = context.require_permits().await;
+    context.issue_transfer_permit(&approver, 10, i64::MAX).await;
+
+    // Transfers up to the threshold need no permit.
+    let small = context.create_transfer_checked_instruction(5).await;
+    let first = context.create_transfer_checked_instruction(10).await;
+    let second = context.create_transfer_checked_instruction(9).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context.expect_success(&[small, first], &[&wallet]).await;
+
+    // The permit is consumed by the first transfer it covers.
+    context
+        .expect_error(
+            &[second],
+            &[&wallet],
+            (0, ProtocolError::TransferPermitRequired),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_mint_halted_by_circuit_breaker() {
+    let mut context = setup_execute().await;
+
+    context.set_circuit_breaker(15, 100).await;
+
+    // The second transfer trips the circuit breaker, halting the mint.
+    let first = context.create_transfer_checked_instruction(10).await;
+    let second = context.create_transfer_checked_instruction(6).await;
+    context
+        .context
+        .expect_success(&[first, second], &[&context.source_owner])
+        .await;
+
+    let instruction = context.create_transfer_checked_instruction(7).await;
+    context
+        .context
+        .expect_error(
+            &[instruction],
+            &[&context.source_owner],
+            (0, ProtocolError::MintHalted),
+        )
+        .await;
+
+    // Once the protocol admin resets the circuit breaker, trading resumes.
+    let admin = context.context.payer.pubkey();
+    let mint = context.mint.pubkey();
+    context
+        .context
+        .expect_success(
+            &[order_tracker::instruction::reset_circuit_breaker(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &mint,
+            )],
+            &[],
+        )
+        .await;
+    let instruction = context.create_transfer_checked_instruction(8).await;
+    context
+        .context
+        .expect_success(&[instruction], &[&context.source_owner])
+        .await;
+}
+
+#[tokio::test]
+async fn fail_mint_halted_self_transfer() {
+    let mut context = setup_execute().await;
+
+    context.set_circuit_breaker(15, 100).await;
+    let instruction = context.create_transfer_checked_instruction(16).await;
+    context
+        .context
+        .expect_success(&[instruction], &[&context.source_owner])
+        .await;
+
+    // A halted mint rejects transfers between the wallets of one profile,
+    // which the circuit breaker does not count.
+    let primary_token_account = context.source;
+    context.switch_to_linked_source_wallet().await;
+    context.destination = primary_token_account;
+    let instruction = context.create_transfer_checked_instruction(10).await;
+    context
+        .context
+        .expect_error(
+            &[instruction],
+            &[&context.source_owner],
+            (0, ProtocolError::MintHalted),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_mint_halted_after_mint_rules_update() {
+    let mut context = setup_execute().await;
+
+    context.set_circuit_breaker(15, 100).await;
+    let instruction = context.create_transfer_checked_instruction(16).await;
+    context
+        .context
+        .expect_success(&[instruction], &[&context.source_owner])
+        .await;
+
+    // The mint authority can't lift the protocol admin's halt.
+    context.update_mint_rules(&MintRules::default()).await;
+    let instruction = context.create_transfer_checked_instruction(10).await;
+    context
+        .context
+        .expect_error(
+            &[instruction],
+            &[&context.source_owner],
+            (0, ProtocolError::MintHalted),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_circuit_breaker_window_elapsed() {
+    let mut context = setup_execute().await;
+
+    context.set_circuit_breaker(15, 100).await;
+
+    let first = context.create_transfer_checked_instruction(10).await;
+    context
+        .context
+        .expect_success(&[first], &[&context.source_owner])
+        .await;
+
+    let mut clock = context
+        .context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap();
+    clock.slot += 100;
+    context.context.set_sysvar(&clock);
+
+    // The window has elapsed, so the volume does not add up to the threshold.
+    let second = context.create_transfer_checked_instruction(10).await;
+    let third = context.create_transfer_checked_instruction(1).await;
+    context
+        .context
+        .expect_success(&[second, third], &[&context.source_owner])
+        .await;
+}
+
+#[tokio::test]
+async fn fail_memo_required() {
+    let mut context = setup_execute().await;
+
+    context
+        .update_mint_rules(&MintRules {
+            memo_threshold: 5,
+            ..MintRules::default()
+        })
+        .await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner: wallet,
+        ..
+    } = context;
+
+    context
+        .expect_error(&[instruction], &[&wallet], (0, ProtocolError::MemoRequired))
+        .await;
+}
+
+#[tokio::test]
+async fn success_memo_recorded() {
+    let mut context = setup_execute().await;
+
+    context
+        .update_mint_rules(&MintRules {
+            memo_threshold: 5,
+            ..MintRules::default()
+        })
+        .await;
+
+    let memo = b"travel rule reference 42";
+    let memo_instruction = spl_memo::build_memo(memo, &[]);
+    let instruction = context.create_transfer_checked_instruction(10).await;
+
+    let ExecuteTestContext {
+        mut context,
+        mint,
+        source_owner,
+        destination_owner,
+        ..
+    } = context;
+
+    context
+        .expect_success(&[memo_instruction, instruction], &[&source_owner])
+        .await;
+
+    let account = context
+        .banks_client
+        .get_account(TransferHistory::address(&mint.pubkey()))
+        .await
+        .unwrap()
+        .unwrap();
+    let history = TransferHistory::try_from_slice(&account.data).unwrap();
+    let entry = history.recent()[0];
+    assert_eq!(entry.source_owner, source_owner.pubkey());
+    assert_eq!(entry.destination_owner, destination_owner.pubkey());
+    assert_eq!(entry.amount, 10);
+    assert_eq!(entry.memo_hash, hash(memo).to_bytes());
+}
+
+#[tokio::test]
+async fn success_transfer_history_wraps() {
+    let context = setup_execute().await;
+
+    let ExecuteTestContext {
+        mut context,
+        mint,
+        source,
+        destination,
+        source_owner,
+        destination_owner,
+    } = context;
+
+    // Fill the mint's history with earlier transfers.
+    let history_address = TransferHistory::address(&mint.pubkey());
+    let mut account = context
+        .banks_client
+        .get_account(history_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let mut history = TransferHistory::default();
+    for amount in 1..=TransferHistory::CAPACITY as u64 {
+        history.push(TransferHistoryEntry {
+            amount,
+            ..TransferHistoryEntry::default()
+        });
+    }
+    account.data = history.try_to_vec().unwrap();
+    context.set_account(&history_address, &account.into());
+
+    let context = ExecuteTestContext {
+        context,
+        mint,
+        source,
+        destination,
+        source_owner,
+        destination_owner,
+    };
+    let instruction = context.create_transfer_checked_instruction(50).await;
+
+    let ExecuteTestContext {
+        mut context,
+        source_owner,
+        destination_owner,
+        ..
+    } = context;
+
+    context
+        .expect_success(&[instruction], &[&source_owner])
+        .await;
+
+    let account = context
+        .banks_client
+        .get_account(history_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let history = TransferHistory::try_from_slice(&account.data).unwrap();
+    assert_eq!(history.count, TransferHistory::CAPACITY as u64 + 1);
+
+    // The oldest transfer was overwritten by the new one.
+    let recent = history.recent();
+    assert_eq!(recent.len(), TransferHistory::CAPACITY);
+    assert_eq!(recent[0].source_owner, source_owner.pubkey());
+    assert_eq!(recent[0].destination_owner, destination_owner.pubkey());
+    assert_eq!(recent[0].memo_hash, [0; 32]);
+    assert_eq!(
+        recent.iter().map(|entry| entry.amount).collect::<Vec<_>>(),
+        std::iter::once(50)
+            .chain((2..=TransferHistory::CAPACITY as u64).rev())
+            .collect::<Vec<_>>()
+    );
+}
+
+impl ExecuteTestContext {
+    async fn set_program_filter(&mut self, mode: ProgramFilterMode, program_ids: &[Pubkey]) {
+        // The source owner is the mint authority.
+        let mint = self.mint.pubkey();
+        let mint_authority = self.source_owner.pubkey();
+        self.context
+            .expect_success(
+                &[order_tracker::instruction::set_program_filter(
+                    &mint,
+                    &mint_authority,
+                    mode,
+                    program_ids,
+                )],
+                &[&self.source_owner],
+            )
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn fail_program_not_allowlisted() {
+    let mut context = setup_execute().await;
+
+    context
+        .set_program_filter(ProgramFilterMode::Allowlist, &[Pubkey::new_unique()])
+        .await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+    context
+        .context
+        .expect_error(
+            &[instruction],
+            &[&context.source_owner],
+            (0, ProtocolError::ProgramNotPermitted),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_program_denylisted() {
+    let mut context = setup_execute().await;
+
+    context
+        .set_program_filter(ProgramFilterMode::Denylist, &[spl_token_2022::id()])
+        .await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+    context
+        .context
+        .expect_error(
+            &[instruction],
+            &[&context.source_owner],
+            (0, ProtocolError::ProgramNotPermitted),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_program_allowlisted() {
+    let mut context = setup_execute().await;
+
+    context
+        .set_program_filter(ProgramFilterMode::Allowlist, &[spl_token_2022::id()])
+        .await;
+
+    let instruction = context.create_transfer_checked_instruction(10).await;
+    context
+        .context
+        .expect_success(&[instruction], &[&context.source_owner])
+        .await;
+}
+
+impl ExecuteTestContext {
+    /// Creates a non-transferable mint, such as a community's membership
+    /// token, and makes it the protocol mint's credential.
+    async fn setup_credential_mint(&mut self) -> Keypair {
+        let credential_mint = Keypair::new();
+        let payer = self.context.payer.pubkey();
+        let space =
+            ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::NonTransferable])
+                .unwrap();
+        let rent = self.context.banks_client.get_rent().await.unwrap();
+        self.context
+            .expect_success(
+                &[
+                    system_instruction::create_account(
+                        &payer,
+                        &credential_mint.pubkey(),
+                        rent.minimum_balance(space),
+                        space as u64,
+                        &spl_token_2022::id(),
+                    ),
+                    spl_token_2022::instruction::initialize_non_transferable_mint(
+                        &spl_token_2022::id(),
+                        &credential_mint.pubkey(),
+                    )
+                    .unwrap(),
+                    spl_token_2022::instruction::initialize_mint2(
+                        &spl_token_2022::id(),
+                        &credential_mint.pubkey(),
+                        &payer,
+                        None,
+                        0,
+                    )
+                    .unwrap(),
+                ],
+                &[&credential_mint],
+            )
+            .await;
+
+        self.update_mint_rules(&MintRules {
+            credential_mint: credential_mint.pubkey(),
+            ..MintRules::default()
+        })
+        .await;
+        credential_mint
+    }
+
+    /// Issues a credential token to a wallet.
+    async fn issue_credential(&mut self, credential_mint: &Pubkey, wallet: &Pubkey) {
+        let payer = self.context.payer.pubkey();
+        self.context
+            .expect_success(
+                &[
+                    spl_associated_token_account::instruction::create_associated_token_account(
+                        &payer,
+                        wallet,
+                        credential_mint,
+                        &spl_token_2022::id(),
+                    ),
+                    spl_token_2022::instruction::mint_to(
+                        &spl_token_2022::id(),
+                        credential_mint,
+                        &get_associated_token_address_with_program_id(
+                            wallet,
+                            credential_mint,
+                            &spl_token_2022::id(),
+                        ),
+                        &payer,
+                        &[],
+                        1,
+                    )
+                    .unwrap(),
+                ],
+                &[],
+            )
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn success_custom_credential_mint() {
+    let mut context = setup_execute().await;
+
+    let credential_mint = context.setup_credential_mint().await;
+    let source_owner = context.source_owner.pubkey();
+    let destination_owner = context.destination_owner.pubkey();
+    context
+        .issue_credential(&credential_mint.pubkey(), &source_owner)
+        .await;
+
+    // The destination owner holds the protocol's soulbound token, but not the
+    // mint's credential.
+    let instruction = context.create_transfer_checked_instruction(10).await;
+    context
+        .context
+        .expect_error(
+            &[instruction],
+            &[&context.source_owner],
+            (0, ProtocolError::SoulboundTokenAccountIsEmpty),
+        )
+        .await;
+
+    context
+        .issue_credential(&credential_mint.pubkey(), &destination_owner)
+        .await;
+
+    let instruction = context.create_transfer_checked_instruction(9).await;
+    context
+        .context
+        .expect_success(&[instruction], &[&context.source_owner])
+        .await;
 }
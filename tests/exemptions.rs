@@ -0,0 +1,264 @@
+#![cfg(feature = "test-sbf")]
+mod context;
+
+use {
+    borsh::BorshDeserialize,
+    context::{
+        setup, setup_empty_protocol_mint_account, setup_empty_protocol_validation_account,
+        setup_wallet, setup_wallet_with_soulbound_token_account, ProtocolTestContext,
+    },
+    order_tracker::{
+        error::ProtocolError,
+        state::{Exemptions, MintRules, DEFAULT_NAMESPACE},
+    },
+    solana_program_test::{tokio, ProgramTestContext},
+    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+const DECIMALS: u8 = 0;
+const NAME: &str = "Joe Token";
+const SYMBOL: &str = "JOE";
+const URI: &str = "https://www.joetoken.com";
+
+async fn setup_mint(context: &mut ProgramTestContext) -> (Keypair, Keypair) {
+    let mint_authority = setup_wallet(context);
+    let mint = setup_empty_protocol_mint_account(context);
+    setup_empty_protocol_validation_account(context, &mint.pubkey());
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::create_mint(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &context.payer.pubkey(),
+                DECIMALS,
+                NAME,
+                SYMBOL,
+                URI,
+                &MintRules::default(),
+            )],
+            &[&mint_authority],
+        )
+        .await;
+
+    (mint, mint_authority)
+}
+
+async fn get_exemptions(context: &mut ProgramTestContext, mint_address: &Pubkey) -> Exemptions {
+    let account = context
+        .banks_client
+        .get_account(Exemptions::address(mint_address))
+        .await
+        .unwrap()
+        .unwrap();
+    Exemptions::try_from_slice(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn fail_incorrect_mint_authority() {
+    let mut context = setup().await;
+
+    let (mint, _) = setup_mint(&mut context).await;
+    let not_mint_authority = setup_wallet(&mut context);
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::add_exemption(
+                &mint.pubkey(),
+                &not_mint_authority.pubkey(),
+                &Pubkey::new_unique(),
+            )],
+            &[&not_mint_authority],
+            (0, ProtocolError::IncorrectMintAuthority),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_owner_already_exempt() {
+    let mut context = setup().await;
+
+    let (mint, mint_authority) = setup_mint(&mut context).await;
+    let owner = Pubkey::new_unique();
+
+    context
+        .expect_error(
+            &[
+                order_tracker::instruction::add_exemption(
+                    &mint.pubkey(),
+                    &mint_authority.pubkey(),
+                    &owner,
+                ),
+                order_tracker::instruction::add_exemption(
+                    &mint.pubkey(),
+                    &mint_authority.pubkey(),
+                    &owner,
+                ),
+            ],
+            &[&mint_authority],
+            (1, ProtocolError::OwnerAlreadyExempt),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_owner_not_exempt() {
+    let mut context = setup().await;
+
+    let (mint, mint_authority) = setup_mint(&mut context).await;
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::remove_exemption(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &Pubkey::new_unique(),
+            )],
+            &[&mint_authority],
+            (0, ProtocolError::OwnerNotExempt),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success() {
+    let mut context = setup().await;
+
+    let (mint, mint_authority) = setup_mint(&mut context).await;
+    let pool = Pubkey::new_unique();
+    let escrow = Pubkey::new_unique();
+
+    context
+        .expect_success(
+            &[
+                order_tracker::instruction::add_exemption(
+                    &mint.pubkey(),
+                    &mint_authority.pubkey(),
+                    &pool,
+                ),
+                order_tracker::instruction::add_exemption(
+                    &mint.pubkey(),
+                    &mint_authority.pubkey(),
+                    &escrow,
+                ),
+                order_tracker::instruction::remove_exemption(
+                    &mint.pubkey(),
+                    &mint_authority.pubkey(),
+                    &escrow,
+                ),
+            ],
+            &[&mint_authority],
+        )
+        .await;
+
+    let exemptions = get_exemptions(&mut context, &mint.pubkey()).await;
+    assert_eq!(exemptions.owners.len(), 1);
+    assert!(exemptions.owners.contains(&pool));
+    assert!(!exemptions.owners.contains(&escrow));
+}
+
+#[tokio::test]
+async fn success_balance_cap_exemptions() {
+    let mut context = setup().await;
+
+    let (mint, mint_authority) = setup_mint(&mut context).await;
+    let treasury = Pubkey::new_unique();
+    let pool = Pubkey::new_unique();
+
+    context
+        .expect_success(
+            &[
+                order_tracker::instruction::add_exemption(
+                    &mint.pubkey(),
+                    &mint_authority.pubkey(),
+                    &pool,
+                ),
+                order_tracker::instruction::add_balance_cap_exemption(
+                    &mint.pubkey(),
+                    &mint_authority.pubkey(),
+                    &treasury,
+                ),
+                order_tracker::instruction::add_balance_cap_exemption(
+                    &mint.pubkey(),
+                    &mint_authority.pubkey(),
+                    &pool,
+                ),
+                order_tracker::instruction::remove_balance_cap_exemption(
+                    &mint.pubkey(),
+                    &mint_authority.pubkey(),
+                    &pool,
+                ),
+            ],
+            &[&mint_authority],
+        )
+        .await;
+
+    // The balance cap exemptions are kept apart from the other exemptions.
+    let exemptions = get_exemptions(&mut context, &mint.pubkey()).await;
+    assert_eq!(exemptions.owners.len(), 1);
+    assert!(exemptions.owners.contains(&pool));
+    assert_eq!(exemptions.balance_cap_owners.len(), 1);
+    assert!(exemptions.balance_cap_owners.contains(&treasury));
+    assert!(!exemptions.owners.contains(&treasury));
+}
+
+#[tokio::test]
+async fn fail_owner_not_balance_cap_exempt() {
+    let mut context = setup().await;
+
+    let (mint, mint_authority) = setup_mint(&mut context).await;
+    let pool = Pubkey::new_unique();
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::add_exemption(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &pool,
+            )],
+            &[&mint_authority],
+        )
+        .await;
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::remove_balance_cap_exemption(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &pool,
+            )],
+            &[&mint_authority],
+            (0, ProtocolError::OwnerNotExempt),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_exempt_owner_initializes_profile() {
+    let mut context = setup().await;
+
+    // Exempting an owner does not stop it from taking a profile of its own.
+    let (mint, mint_authority) = setup_mint(&mut context).await;
+    let wallet = setup_wallet_with_soulbound_token_account(&mut context);
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::add_exemption(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &wallet.pubkey(),
+            )],
+            &[&mint_authority],
+        )
+        .await;
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::initialize_profile(
+                &DEFAULT_NAMESPACE,
+                &wallet.pubkey(),
+            )],
+            &[&wallet],
+        )
+        .await;
+}
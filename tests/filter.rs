@@ -0,0 +1,124 @@
+#![cfg(feature = "test-sbf")]
+mod context;
+
+use {
+    borsh::BorshDeserialize,
+    context::{
+        setup, setup_empty_protocol_mint_account, setup_empty_protocol_validation_account,
+        setup_wallet, ProtocolTestContext,
+    },
+    order_tracker::{
+        error::ProtocolError,
+        state::{MintRules, ProgramFilter, ProgramFilterMode},
+    },
+    solana_program_test::{tokio, ProgramTestContext},
+    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+const DECIMALS: u8 = 0;
+const NAME: &str = "Joe Token";
+const SYMBOL: &str = "JOE";
+const URI: &str = "https://www.joetoken.com";
+
+async fn setup_mint(context: &mut ProgramTestContext) -> (Keypair, Keypair) {
+    let mint_authority = setup_wallet(context);
+    let mint = setup_empty_protocol_mint_account(context);
+    setup_empty_protocol_validation_account(context, &mint.pubkey());
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::create_mint(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &context.payer.pubkey(),
+                DECIMALS,
+                NAME,
+                SYMBOL,
+                URI,
+                &MintRules::default(),
+            )],
+            &[&mint_authority],
+        )
+        .await;
+
+    (mint, mint_authority)
+}
+
+async fn get_program_filter(
+    context: &mut ProgramTestContext,
+    mint_address: &Pubkey,
+) -> ProgramFilter {
+    let account = context
+        .banks_client
+        .get_account(ProgramFilter::address(mint_address))
+        .await
+        .unwrap()
+        .unwrap();
+    ProgramFilter::try_from_slice(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn fail_incorrect_mint_authority() {
+    let mut context = setup().await;
+
+    let (mint, _) = setup_mint(&mut context).await;
+    let not_mint_authority = setup_wallet(&mut context);
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::set_program_filter(
+                &mint.pubkey(),
+                &not_mint_authority.pubkey(),
+                ProgramFilterMode::Allowlist,
+                &[spl_token_2022::id()],
+            )],
+            &[&not_mint_authority],
+            (0, ProtocolError::IncorrectMintAuthority),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_set_program_filter() {
+    let mut context = setup().await;
+
+    let (mint, mint_authority) = setup_mint(&mut context).await;
+    let settlement_program = Pubkey::new_unique();
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::set_program_filter(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                ProgramFilterMode::Allowlist,
+                &[spl_token_2022::id(), settlement_program],
+            )],
+            &[&mint_authority],
+        )
+        .await;
+
+    let filter = get_program_filter(&mut context, &mint.pubkey()).await;
+    assert_eq!(filter.mode, ProgramFilterMode::Allowlist);
+    assert!(filter.permits(&spl_token_2022::id()));
+    assert!(filter.permits(&settlement_program));
+    assert!(!filter.permits(&Pubkey::new_unique()));
+
+    // Setting the filter again replaces it.
+    context
+        .expect_success(
+            &[order_tracker::instruction::set_program_filter(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                ProgramFilterMode::Denylist,
+                &[settlement_program],
+            )],
+            &[&mint_authority],
+        )
+        .await;
+
+    let filter = get_program_filter(&mut context, &mint.pubkey()).await;
+    assert_eq!(filter.mode, ProgramFilterMode::Denylist);
+    assert_eq!(filter.program_ids.len(), 1);
+    assert!(filter.permits(&spl_token_2022::id()));
+    assert!(!filter.permits(&settlement_program));
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "test-sbf")]
+mod context;
+
+use {
+    context::{get_profile, setup, setup_profile, setup_wallet, ProtocolTestContext},
+    order_tracker::{error::ProtocolError, state::DEFAULT_NAMESPACE},
+    solana_program_test::tokio,
+    solana_sdk::signer::Signer,
+};
+
+#[tokio::test]
+async fn fail_incorrect_admin() {
+    let mut context = setup().await;
+
+    let wallet = setup_profile(&mut context).await;
+    let not_admin = setup_wallet(&mut context);
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::freeze_profile(
+                &DEFAULT_NAMESPACE,
+                &not_admin.pubkey(),
+                &wallet.pubkey(),
+            )],
+            &[&not_admin],
+            (0, ProtocolError::IncorrectAdmin),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_profile_not_initialized() {
+    let mut context = setup().await;
+
+    let admin = context.payer.pubkey();
+    let wallet = setup_wallet(&mut context);
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::freeze_profile(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &wallet.pubkey(),
+            )],
+            &[],
+            (0, ProtocolError::ProfileNotInitialized),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_freeze_and_thaw() {
+    let mut context = setup().await;
+
+    let admin = context.payer.pubkey();
+    let wallet = setup_profile(&mut context).await;
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::freeze_profile(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &wallet.pubkey(),
+            )],
+            &[],
+        )
+        .await;
+    assert!(get_profile(&mut context, &wallet.pubkey()).await.frozen);
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::thaw_profile(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &wallet.pubkey(),
+            )],
+            &[],
+        )
+        .await;
+    assert!(!get_profile(&mut context, &wallet.pubkey()).await.frozen);
+}
@@ -0,0 +1,298 @@
+#![cfg(feature = "test-sbf")]
+mod context;
+
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    context::{
+        get_profile, get_wallet_link, setup, setup_profile, setup_wallet,
+        setup_wallet_with_soulbound_token_account, ProtocolTestContext,
+    },
+    order_tracker::{
+        error::ProtocolError,
+        state::{Profile, DEFAULT_NAMESPACE},
+    },
+    solana_program::program_error::ProgramError,
+    solana_program_test::tokio,
+    solana_sdk::signer::Signer,
+};
+
+#[tokio::test]
+async fn fail_primary_profile_not_initialized() {
+    let mut context = setup().await;
+
+    let primary = setup_wallet(&mut context);
+    let secondary = setup_wallet(&mut context);
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::link_wallet(
+                &DEFAULT_NAMESPACE,
+                &primary.pubkey(),
+                &secondary.pubkey(),
+            )],
+            &[&primary, &secondary],
+            (0, ProtocolError::ProfileNotInitialized),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_secondary_has_profile() {
+    let mut context = setup().await;
+
+    let primary = setup_profile(&mut context).await;
+    let secondary = setup_profile(&mut context).await;
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::link_wallet(
+                &DEFAULT_NAMESPACE,
+                &primary.pubkey(),
+                &secondary.pubkey(),
+            )],
+            &[&primary, &secondary],
+            (0, ProtocolError::WalletHasProfile),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_secondary_linked_to_other_profile() {
+    let mut context = setup().await;
+
+    let primary = setup_profile(&mut context).await;
+    let other_primary = setup_profile(&mut context).await;
+    let secondary = setup_wallet(&mut context);
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::link_wallet(
+                &DEFAULT_NAMESPACE,
+                &other_primary.pubkey(),
+                &secondary.pubkey(),
+            )],
+            &[&other_primary, &secondary],
+        )
+        .await;
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::link_wallet(
+                &DEFAULT_NAMESPACE,
+                &primary.pubkey(),
+                &secondary.pubkey(),
+            )],
+            &[&primary, &secondary],
+            (0, ProtocolError::WalletAlreadyLinked),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_primary_profile_frozen() {
+    let mut context = setup().await;
+
+    let primary = setup_profile(&mut context).await;
+    let secondary = setup_wallet(&mut context);
+    let admin = context.payer.pubkey();
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::freeze_profile(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &primary.pubkey(),
+            )],
+            &[],
+        )
+        .await;
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::link_wallet(
+                &DEFAULT_NAMESPACE,
+                &primary.pubkey(),
+                &secondary.pubkey(),
+            )],
+            &[&primary, &secondary],
+            (0, ProtocolError::ProfileFrozen),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_primary_profile_flagged() {
+    let mut context = setup().await;
+
+    let primary = setup_profile(&mut context).await;
+    let secondary = setup_wallet(&mut context);
+
+    // Flag the primary wallet's profile for wash trading.
+    let address = Profile::address(&DEFAULT_NAMESPACE, &primary.pubkey());
+    let mut account = context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .unwrap();
+    let mut profile = Profile::try_from_slice(&account.data).unwrap();
+    profile.wash_trading_flagged = true;
+    account.data = profile.try_to_vec().unwrap();
+    context.set_account(&address, &account.into());
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::link_wallet(
+                &DEFAULT_NAMESPACE,
+                &primary.pubkey(),
+                &secondary.pubkey(),
+            )],
+            &[&primary, &secondary],
+            (0, ProtocolError::WashTradingFlagged),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_secondary_not_signer() {
+    let mut context = setup().await;
+
+    let primary = setup_profile(&mut context).await;
+    let secondary = setup_wallet(&mut context);
+
+    let mut instruction = order_tracker::instruction::link_wallet(
+        &DEFAULT_NAMESPACE,
+        &primary.pubkey(),
+        &secondary.pubkey(),
+    );
+    instruction.accounts[3].is_signer = false;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&primary],
+            (0, ProgramError::MissingRequiredSignature),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_linked_wallet_initializes_profile() {
+    let mut context = setup().await;
+
+    let primary = setup_profile(&mut context).await;
+    let secondary = setup_wallet_with_soulbound_token_account(&mut context);
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::link_wallet(
+                &DEFAULT_NAMESPACE,
+                &primary.pubkey(),
+                &secondary.pubkey(),
+            )],
+            &[&primary, &secondary],
+        )
+        .await;
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::initialize_profile(
+                &DEFAULT_NAMESPACE,
+                &secondary.pubkey(),
+            )],
+            &[&secondary],
+            (0, ProtocolError::WalletAlreadyLinked),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success() {
+    let mut context = setup().await;
+
+    let primary = setup_profile(&mut context).await;
+    let secondary = setup_wallet(&mut context);
+
+    // A new profile is linked to its own wallet.
+    let link = get_wallet_link(&mut context, &primary.pubkey()).await;
+    assert_eq!(link.primary_wallet, primary.pubkey());
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::link_wallet(
+                &DEFAULT_NAMESPACE,
+                &primary.pubkey(),
+                &secondary.pubkey(),
+            )],
+            &[&primary, &secondary],
+        )
+        .await;
+
+    let link = get_wallet_link(&mut context, &secondary.pubkey()).await;
+    assert_eq!(link.primary_wallet, primary.pubkey());
+
+    // The secondary wallet trades under the primary wallet's profile, and is
+    // not given one of its own.
+    assert_eq!(
+        get_profile(&mut context, &primary.pubkey())
+            .await
+            .linked_wallets,
+        1
+    );
+    assert!(context
+        .banks_client
+        .get_account(Profile::address(&DEFAULT_NAMESPACE, &secondary.pubkey()))
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn success_initialize_wallet_link() {
+    let mut context = setup().await;
+
+    let primary = setup_profile(&mut context).await;
+    let wallet = setup_wallet(&mut context);
+    let payer = context.payer.pubkey();
+
+    // Anyone may link a wallet without a profile to itself.
+    context
+        .expect_success(
+            &[order_tracker::instruction::initialize_wallet_link(
+                &DEFAULT_NAMESPACE,
+                &payer,
+                &wallet.pubkey(),
+            )],
+            &[],
+        )
+        .await;
+    let link = get_wallet_link(&mut context, &wallet.pubkey()).await;
+    assert_eq!(link.primary_wallet, wallet.pubkey());
+
+    // The self-link doesn't keep the wallet from being linked to a primary
+    // wallet.
+    context
+        .expect_success(
+            &[order_tracker::instruction::link_wallet(
+                &DEFAULT_NAMESPACE,
+                &primary.pubkey(),
+                &wallet.pubkey(),
+            )],
+            &[&primary, &wallet],
+        )
+        .await;
+
+    // Initializing the link again leaves the existing link in place.
+    context
+        .expect_success(
+            &[order_tracker::instruction::initialize_wallet_link(
+                &DEFAULT_NAMESPACE,
+                &payer,
+                &wallet.pubkey(),
+            )],
+            &[],
+        )
+        .await;
+    let link = get_wallet_link(&mut context, &wallet.pubkey()).await;
+    assert_eq!(link.primary_wallet, primary.pubkey());
+}
@@ -0,0 +1,154 @@
+#![cfg(feature = "test-sbf")]
+mod context;
+
+use {
+    borsh::BorshDeserialize,
+    context::{
+        setup, setup_empty_protocol_mint_account, setup_empty_protocol_validation_account,
+        setup_wallet, ProtocolTestContext,
+    },
+    order_tracker::{
+        error::ProtocolError,
+        state::{Lockup, MintRules},
+    },
+    solana_program_test::{tokio, ProgramTestContext},
+    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+const DECIMALS: u8 = 0;
+const NAME: &str = "Joe Token";
+const SYMBOL: &str = "JOE";
+const URI: &str = "https://www.joetoken.com";
+
+async fn setup_mint(context: &mut ProgramTestContext) -> (Keypair, Keypair) {
+    let mint_authority = setup_wallet(context);
+    let mint = setup_empty_protocol_mint_account(context);
+    setup_empty_protocol_validation_account(context, &mint.pubkey());
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::create_mint(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &context.payer.pubkey(),
+                DECIMALS,
+                NAME,
+                SYMBOL,
+                URI,
+                &MintRules::default(),
+            )],
+            &[&mint_authority],
+        )
+        .await;
+
+    (mint, mint_authority)
+}
+
+async fn get_lockup(context: &mut ProgramTestContext, wallet: &Pubkey, mint: &Pubkey) -> Lockup {
+    let account = context
+        .banks_client
+        .get_account(Lockup::address(wallet, mint))
+        .await
+        .unwrap()
+        .unwrap();
+    Lockup::try_from_slice(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn fail_incorrect_mint_authority() {
+    let mut context = setup().await;
+
+    let (mint, _) = setup_mint(&mut context).await;
+    let not_mint_authority = setup_wallet(&mut context);
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::set_lockup(
+                &mint.pubkey(),
+                &not_mint_authority.pubkey(),
+                &Pubkey::new_unique(),
+                &Lockup {
+                    locked_amount: 100,
+                    unlock_start: 1_000,
+                    unlock_end: 2_000,
+                },
+            )],
+            &[&not_mint_authority],
+            (0, ProtocolError::IncorrectMintAuthority),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_invalid_unlock_schedule() {
+    let mut context = setup().await;
+
+    let (mint, mint_authority) = setup_mint(&mut context).await;
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::set_lockup(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &Pubkey::new_unique(),
+                &Lockup {
+                    locked_amount: 100,
+                    unlock_start: 2_000,
+                    unlock_end: 1_000,
+                },
+            )],
+            &[&mint_authority],
+            (0, ProtocolError::InvalidUnlockSchedule),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_set_and_replace() {
+    let mut context = setup().await;
+
+    let (mint, mint_authority) = setup_mint(&mut context).await;
+    let wallet = Pubkey::new_unique();
+
+    let lockup = Lockup {
+        locked_amount: 100,
+        unlock_start: 1_000,
+        unlock_end: 2_000,
+    };
+    context
+        .expect_success(
+            &[order_tracker::instruction::set_lockup(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &wallet,
+                &lockup,
+            )],
+            &[&mint_authority],
+        )
+        .await;
+    assert_eq!(
+        get_lockup(&mut context, &wallet, &mint.pubkey()).await,
+        lockup
+    );
+
+    let lockup = Lockup {
+        locked_amount: 50,
+        unlock_start: 1_000,
+        unlock_end: 1_000,
+    };
+    context
+        .expect_success(
+            &[order_tracker::instruction::set_lockup(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &wallet,
+                &lockup,
+            )],
+            &[&mint_authority],
+        )
+        .await;
+    assert_eq!(
+        get_lockup(&mut context, &wallet, &mint.pubkey()).await,
+        lockup
+    );
+}
@@ -6,7 +6,7 @@ use {
         setup, setup_empty_protocol_mint_account, setup_empty_protocol_validation_account,
         setup_wallet, ProtocolTestContext,
     },
-    order_tracker::error::ProtocolError,
+    order_tracker::{error::ProtocolError, state::MintRules},
     solana_program::{program_error::ProgramError, pubkey::Pubkey},
     solana_program_test::tokio,
     solana_sdk::signer::Signer,
@@ -29,10 +29,12 @@ async fn fail_incorrect_validation_account() {
     let mut instruction = order_tracker::instruction::create_mint(
         &mint.pubkey(),
         &wallet.pubkey(),
+        &context.payer.pubkey(),
         DECIMALS,
         NAME,
         SYMBOL,
         URI,
+        &MintRules::default(),
     );
     instruction.accounts[1].pubkey = Pubkey::new_unique();
 
@@ -57,10 +59,12 @@ async fn fail_payer_not_signer() {
     let mut instruction = order_tracker::instruction::create_mint(
         &mint.pubkey(),
         &wallet.pubkey(),
+        &context.payer.pubkey(),
         DECIMALS,
         NAME,
         SYMBOL,
         URI,
+        &MintRules::default(),
     );
     instruction.accounts[2].is_signer = false;
 
@@ -73,6 +77,38 @@ async fn fail_payer_not_signer() {
         .await;
 }
 
+// Fail credential mint not a non-transferable mint
+#[tokio::test]
+async fn fail_incorrect_credential_mint() {
+    let mut context = setup().await;
+
+    let wallet = setup_wallet(&mut context);
+    let mint = setup_empty_protocol_mint_account(&mut context);
+    setup_empty_protocol_validation_account(&mut context, &mint.pubkey());
+
+    let instruction = order_tracker::instruction::create_mint(
+        &mint.pubkey(),
+        &wallet.pubkey(),
+        &context.payer.pubkey(),
+        DECIMALS,
+        NAME,
+        SYMBOL,
+        URI,
+        &MintRules {
+            credential_mint: Pubkey::new_unique(),
+            ..MintRules::default()
+        },
+    );
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&wallet],
+            (0, ProtocolError::IncorrectCredentialMint),
+        )
+        .await;
+}
+
 // Success
 #[tokio::test]
 async fn success() {
@@ -85,10 +121,12 @@ async fn success() {
     let instruction = order_tracker::instruction::create_mint(
         &mint.pubkey(),
         &wallet.pubkey(),
+        &context.payer.pubkey(),
         DECIMALS,
         NAME,
         SYMBOL,
         URI,
+        &MintRules::default(),
     );
 
     context.expect_success(&[instruction], &[&wallet]).await;
@@ -0,0 +1,311 @@
+#![cfg(feature = "test-sbf")]
+mod context;
+
+use {
+    borsh::BorshDeserialize,
+    context::{
+        setup, setup_empty_protocol_mint_account, setup_empty_protocol_validation_account,
+        setup_namespace, setup_namespaced_soulbound_token_account, setup_wallet,
+        setup_wallet_with_soulbound_token_account, ProtocolTestContext,
+    },
+    order_tracker::{
+        error::ProtocolError,
+        state::{MintAuthority, MintRules, OrderTracker, Profile, Soulbound, DEFAULT_NAMESPACE},
+    },
+    solana_program::program_error::ProgramError,
+    solana_program_test::{tokio, ProgramTestContext},
+    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+async fn setup_other_namespace(context: &mut ProgramTestContext) -> (Pubkey, Keypair) {
+    let namespace = Keypair::new();
+    let admin = setup_wallet(context);
+    setup_namespace(context, &namespace, &admin).await;
+    (namespace.pubkey(), admin)
+}
+
+async fn setup_profile(context: &mut ProgramTestContext, namespace: &Pubkey, wallet: &Keypair) {
+    setup_namespaced_soulbound_token_account(context, namespace, &wallet.pubkey(), 0);
+    context
+        .expect_success(
+            &[order_tracker::instruction::initialize_profile(
+                namespace,
+                &wallet.pubkey(),
+            )],
+            &[wallet],
+        )
+        .await;
+}
+
+async fn get_profile(
+    context: &mut ProgramTestContext,
+    namespace: &Pubkey,
+    wallet_address: &Pubkey,
+) -> Profile {
+    let account = context
+        .banks_client
+        .get_account(Profile::address(namespace, wallet_address))
+        .await
+        .unwrap()
+        .unwrap();
+    Profile::try_from_slice(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn fail_claim_namespace_without_key() {
+    let mut context = setup().await;
+
+    let namespace = Pubkey::new_unique();
+    let attacker = setup_wallet(&mut context);
+
+    let mut instruction =
+        order_tracker::instruction::initialize_protocol(&namespace, &attacker.pubkey());
+    instruction.accounts[7].is_signer = false;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&attacker],
+            (0, ProgramError::MissingRequiredSignature),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_claim_default_namespace() {
+    let mut context = setup().await;
+
+    // Only the program's upgrade authority, here the payer, may claim the
+    // default namespace.
+    let attacker = setup_wallet(&mut context);
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::initialize_protocol(
+                &DEFAULT_NAMESPACE,
+                &attacker.pubkey(),
+            )],
+            &[&attacker],
+            (0, ProtocolError::IncorrectUpgradeAuthority),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_initialize_creates_accounts() {
+    let mut context = setup().await;
+
+    let (namespace, _) = setup_other_namespace(&mut context).await;
+
+    let soulbound_mint = context
+        .banks_client
+        .get_account(Soulbound::address(&namespace))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(soulbound_mint.owner, spl_token_2022::id());
+    let mint_authority = context
+        .banks_client
+        .get_account(MintAuthority::address(&namespace))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(mint_authority.owner, order_tracker::id());
+    let order_tracker = context
+        .banks_client
+        .get_account(OrderTracker::address(&namespace))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(order_tracker.owner, order_tracker::id());
+    let order_tracker = OrderTracker::try_from_slice(&order_tracker.data).unwrap();
+    assert!(order_tracker.volume.is_empty());
+}
+
+#[tokio::test]
+async fn fail_create_mint_by_admin_of_other_namespace() {
+    let mut context = setup().await;
+
+    let (_, other_admin) = setup_other_namespace(&mut context).await;
+    let mint_authority = setup_wallet(&mut context);
+    let mint = setup_empty_protocol_mint_account(&mut context);
+    setup_empty_protocol_validation_account(&mut context, &mint.pubkey());
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::create_mint(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &other_admin.pubkey(),
+                0,
+                "Joe Token",
+                "JOE",
+                "https://www.joetoken.com",
+                &MintRules::default(),
+            )],
+            &[&mint_authority, &other_admin],
+            (0, ProtocolError::IncorrectAdmin),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_create_mint_admin_not_signer() {
+    let mut context = setup().await;
+
+    let (namespace, admin) = setup_other_namespace(&mut context).await;
+    let mint_authority = setup_wallet(&mut context);
+    let mint = setup_empty_protocol_mint_account(&mut context);
+    setup_empty_protocol_validation_account(&mut context, &mint.pubkey());
+
+    let mut instruction = order_tracker::instruction::create_mint(
+        &mint.pubkey(),
+        &mint_authority.pubkey(),
+        &admin.pubkey(),
+        0,
+        "Joe Token",
+        "JOE",
+        "https://www.joetoken.com",
+        &MintRules::new(&namespace),
+    );
+    instruction.accounts[10].is_signer = false;
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&mint_authority],
+            (0, ProgramError::MissingRequiredSignature),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_create_mint_in_namespace() {
+    let mut context = setup().await;
+
+    let (namespace, admin) = setup_other_namespace(&mut context).await;
+    let mint_authority = setup_wallet(&mut context);
+    let mint = setup_empty_protocol_mint_account(&mut context);
+    setup_empty_protocol_validation_account(&mut context, &mint.pubkey());
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::create_mint(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &admin.pubkey(),
+                0,
+                "Joe Token",
+                "JOE",
+                "https://www.joetoken.com",
+                &MintRules::new(&namespace),
+            )],
+            &[&mint_authority, &admin],
+        )
+        .await;
+
+    // The mint is registered in its namespace's order tracker.
+    let account = context
+        .banks_client
+        .get_account(OrderTracker::address(&namespace))
+        .await
+        .unwrap()
+        .unwrap();
+    let order_tracker = OrderTracker::try_from_slice(&account.data).unwrap();
+    assert_eq!(order_tracker.volume.get(&mint.pubkey()), Some(&0));
+}
+
+#[tokio::test]
+async fn fail_admin_of_other_namespace() {
+    let mut context = setup().await;
+
+    let (namespace, _) = setup_other_namespace(&mut context).await;
+    let default_admin = context.payer.pubkey();
+    let wallet = setup_wallet(&mut context);
+    setup_profile(&mut context, &namespace, &wallet).await;
+
+    // The default namespace's admin is not the other namespace's admin.
+    context
+        .expect_error(
+            &[order_tracker::instruction::freeze_profile(
+                &namespace,
+                &default_admin,
+                &wallet.pubkey(),
+            )],
+            &[],
+            (0, ProtocolError::IncorrectAdmin),
+        )
+        .await;
+
+    // Nor can the default namespace's config be used on a profile in the
+    // other namespace.
+    let mut instruction = order_tracker::instruction::freeze_profile(
+        &DEFAULT_NAMESPACE,
+        &default_admin,
+        &wallet.pubkey(),
+    );
+    instruction.accounts[2].pubkey = Profile::address(&namespace, &wallet.pubkey());
+    context
+        .expect_error(&[instruction], &[], (0, ProtocolError::IncorrectNamespace))
+        .await;
+}
+
+#[tokio::test]
+async fn success_admin_of_namespace() {
+    let mut context = setup().await;
+
+    let (namespace, admin) = setup_other_namespace(&mut context).await;
+    let wallet = setup_wallet(&mut context);
+    setup_profile(&mut context, &namespace, &wallet).await;
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::freeze_profile(
+                &namespace,
+                &admin.pubkey(),
+                &wallet.pubkey(),
+            )],
+            &[&admin],
+        )
+        .await;
+
+    let profile = get_profile(&mut context, &namespace, &wallet.pubkey()).await;
+    assert!(profile.frozen);
+}
+
+#[tokio::test]
+async fn success_profiles_in_both_namespaces() {
+    let mut context = setup().await;
+
+    let (namespace, admin) = setup_other_namespace(&mut context).await;
+    let wallet = setup_wallet_with_soulbound_token_account(&mut context);
+    context
+        .expect_success(
+            &[order_tracker::instruction::initialize_profile(
+                &DEFAULT_NAMESPACE,
+                &wallet.pubkey(),
+            )],
+            &[&wallet],
+        )
+        .await;
+    setup_profile(&mut context, &namespace, &wallet).await;
+
+    // Freezing the wallet's profile in one namespace leaves the other alone.
+    context
+        .expect_success(
+            &[order_tracker::instruction::freeze_profile(
+                &namespace,
+                &admin.pubkey(),
+                &wallet.pubkey(),
+            )],
+            &[&admin],
+        )
+        .await;
+
+    let profile = get_profile(&mut context, &namespace, &wallet.pubkey()).await;
+    assert_eq!(profile.wallet_address, wallet.pubkey());
+    assert!(profile.frozen);
+    let profile = get_profile(&mut context, &DEFAULT_NAMESPACE, &wallet.pubkey()).await;
+    assert_eq!(profile.wallet_address, wallet.pubkey());
+    assert!(!profile.frozen);
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "test-sbf")]
+mod context;
+
+use {
+    base64::{engine::general_purpose::STANDARD, Engine},
+    borsh::BorshSerialize,
+    context::{setup, setup_soulbound_token_account, setup_wallet, ProtocolTestContext},
+    order_tracker::{
+        error::ProtocolError,
+        events::{ProtocolEvent, VersionedEvent},
+        state::DEFAULT_NAMESPACE,
+    },
+    solana_program::pubkey::Pubkey,
+    solana_program_test::tokio,
+    solana_sdk::{signer::Signer, transaction::TransactionError},
+};
+
+fn program_data(event: ProtocolEvent) -> String {
+    let data = VersionedEvent::V1(event).try_to_vec().unwrap();
+    format!("Program data: {}", STANDARD.encode(data))
+}
+
+#[test]
+fn success_parse_events() {
+    let program_id = order_tracker::id();
+    let other_program_id = Pubkey::new_unique();
+    let wallet = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let logs = vec![
+        format!("Program {other_program_id} invoke [1]"),
+        program_data(ProtocolEvent::MintRulesUpdated { mint }),
+        format!("Program {other_program_id} success"),
+        format!("Program {program_id} invoke [1]"),
+        "Program log: Instruction: InitializeProfile".to_string(),
+        format!("Program {other_program_id} invoke [2]"),
+        program_data(ProtocolEvent::MintRulesUpdated { mint }),
+        format!("Program {other_program_id} consumed 100 of 200000 compute units"),
+        format!("Program {other_program_id} success"),
+        program_data(ProtocolEvent::ProfileInitialized {
+            namespace: DEFAULT_NAMESPACE,
+            wallet,
+        }),
+        format!("Program {program_id} consumed 1000 of 200000 compute units"),
+        format!("Program {program_id} success"),
+    ];
+
+    assert_eq!(
+        order_tracker::offchain::parse_events(&logs)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap(),
+        vec![ProtocolEvent::ProfileInitialized {
+            namespace: DEFAULT_NAMESPACE,
+            wallet,
+        }]
+    );
+    assert_eq!(
+        order_tracker::offchain::parse_events_with_program_id(&logs, &other_program_id)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap(),
+        vec![
+            ProtocolEvent::MintRulesUpdated { mint },
+            ProtocolEvent::MintRulesUpdated { mint },
+        ]
+    );
+}
+
+#[test]
+fn fail_parse_events_invalid_data() {
+    let program_id = order_tracker::id();
+    let mint = Pubkey::new_unique();
+
+    let logs = vec![
+        format!("Program {program_id} invoke [1]"),
+        program_data(ProtocolEvent::MintRulesUpdated { mint }),
+        format!("Program data: {}", STANDARD.encode([u8::MAX])),
+        program_data(ProtocolEvent::CircuitBreakerReset {
+            namespace: DEFAULT_NAMESPACE,
+            mint,
+        }),
+        format!("Program {program_id} success"),
+    ];
+
+    // Only the undecodable entry fails; the events around it are still decoded.
+    let events = order_tracker::offchain::parse_events(&logs);
+    assert_eq!(events.len(), 3);
+    assert_eq!(
+        events[0].as_ref().unwrap(),
+        &ProtocolEvent::MintRulesUpdated { mint }
+    );
+    assert!(events[1].is_err());
+    assert_eq!(
+        events[2].as_ref().unwrap(),
+        &ProtocolEvent::CircuitBreakerReset {
+            namespace: DEFAULT_NAMESPACE,
+            mint,
+        }
+    );
+}
+
+#[tokio::test]
+async fn success_decode_instruction_error() {
+    let mut context = setup().await;
+
+    let wallet = setup_wallet(&mut context);
+    setup_soulbound_token_account(&mut context, &wallet.pubkey(), 1);
+
+    let transaction = context.default_transaction(
+        &[order_tracker::instruction::initialize_profile(
+            &DEFAULT_NAMESPACE,
+            &wallet.pubkey(),
+        )],
+        &[&wallet],
+    );
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    let TransactionError::InstructionError(0, err) = err else {
+        panic!("Expected an instruction error, got {err:?}");
+    };
+    assert_eq!(
+        order_tracker::offchain::decode_instruction_error(&err),
+        Some(ProtocolError::SoulboundTokenAccountHasTokens)
+    );
+}
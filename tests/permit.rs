@@ -0,0 +1,120 @@
+#![cfg(feature = "test-sbf")]
+mod context;
+
+use {
+    borsh::BorshDeserialize,
+    context::{
+        setup, setup_empty_protocol_mint_account, setup_empty_protocol_validation_account,
+        setup_wallet, ProtocolTestContext,
+    },
+    order_tracker::{
+        error::ProtocolError,
+        state::{MintRules, TransferPermit},
+    },
+    solana_program_test::{tokio, ProgramTestContext},
+    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+const DECIMALS: u8 = 0;
+const NAME: &str = "Joe Token";
+const SYMBOL: &str = "JOE";
+const URI: &str = "https://www.joetoken.com";
+
+async fn setup_mint(context: &mut ProgramTestContext, approver: &Pubkey) -> Keypair {
+    let mint_authority = setup_wallet(context);
+    let mint = setup_empty_protocol_mint_account(context);
+    setup_empty_protocol_validation_account(context, &mint.pubkey());
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::create_mint(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &context.payer.pubkey(),
+                DECIMALS,
+                NAME,
+                SYMBOL,
+                URI,
+                &MintRules {
+                    permit_threshold: 1_000,
+                    permit_approver: *approver,
+                    ..MintRules::default()
+                },
+            )],
+            &[&mint_authority],
+        )
+        .await;
+
+    mint
+}
+
+async fn get_transfer_permit(
+    context: &mut ProgramTestContext,
+    source: &Pubkey,
+    destination: &Pubkey,
+    mint: &Pubkey,
+) -> TransferPermit {
+    let account = context
+        .banks_client
+        .get_account(TransferPermit::address(source, destination, mint))
+        .await
+        .unwrap()
+        .unwrap();
+    TransferPermit::try_from_slice(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn fail_incorrect_permit_approver() {
+    let mut context = setup().await;
+
+    let approver = setup_wallet(&mut context);
+    let mint = setup_mint(&mut context, &approver.pubkey()).await;
+    let not_approver = setup_wallet(&mut context);
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::issue_transfer_permit(
+                &mint.pubkey(),
+                &not_approver.pubkey(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                5_000,
+                i64::MAX,
+            )],
+            &[&not_approver],
+            (0, ProtocolError::IncorrectPermitApprover),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_issue_and_replace() {
+    let mut context = setup().await;
+
+    let approver = setup_wallet(&mut context);
+    let mint = setup_mint(&mut context, &approver.pubkey()).await;
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    for (max_amount, expiry) in [(5_000, 1_000), (8_000, 2_000)] {
+        context
+            .expect_success(
+                &[order_tracker::instruction::issue_transfer_permit(
+                    &mint.pubkey(),
+                    &approver.pubkey(),
+                    &source,
+                    &destination,
+                    max_amount,
+                    expiry,
+                )],
+                &[&approver],
+            )
+            .await;
+
+        let permit = get_transfer_permit(&mut context, &source, &destination, &mint.pubkey()).await;
+        assert_eq!(permit.approver, approver.pubkey());
+        assert_eq!(permit.max_amount, max_amount);
+        assert_eq!(permit.expiry, expiry);
+        assert!(!permit.consumed);
+    }
+}
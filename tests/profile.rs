@@ -2,23 +2,42 @@
 mod context;
 
 use {
+    borsh::BorshSerialize,
     context::{
         setup, setup_soulbound_token_account, setup_wallet,
         setup_wallet_with_soulbound_token_account, ProtocolTestContext,
     },
-    order_tracker::{error::ProtocolError, state::Profile},
+    order_tracker::{
+        error::ProtocolError,
+        events::ProtocolEvent,
+        state::{Profile, WalletLink, DEFAULT_NAMESPACE},
+    },
     solana_program::{program_error::ProgramError, pubkey::Pubkey},
-    solana_program_test::tokio,
+    solana_program_test::{tokio, ProgramTestContext},
     solana_sdk::{account::Account, signer::Signer},
 };
 
+fn set_wallet_link(context: &mut ProgramTestContext, wallet: &Pubkey, primary_wallet: &Pubkey) {
+    context.set_account(
+        &WalletLink::address(&DEFAULT_NAMESPACE, wallet),
+        &Account {
+            lamports: 1_000_000_000,
+            data: WalletLink::new(primary_wallet).try_to_vec().unwrap(),
+            owner: order_tracker::id(),
+            ..Account::default()
+        }
+        .into(),
+    );
+}
+
 #[tokio::test]
 async fn fail_incorrect_soulbound_mint() {
     let mut context = setup().await;
 
     let wallet = setup_wallet(&mut context);
 
-    let mut instruction = order_tracker::instruction::initialize_profile(&wallet.pubkey());
+    let mut instruction =
+        order_tracker::instruction::initialize_profile(&DEFAULT_NAMESPACE, &wallet.pubkey());
     instruction.accounts[0].pubkey = Pubkey::new_unique();
 
     context
@@ -36,7 +55,8 @@ async fn fail_incorrect_soulbound_token_account() {
 
     let wallet = setup_wallet(&mut context);
 
-    let mut instruction = order_tracker::instruction::initialize_profile(&wallet.pubkey());
+    let mut instruction =
+        order_tracker::instruction::initialize_profile(&DEFAULT_NAMESPACE, &wallet.pubkey());
     instruction.accounts[1].pubkey = Pubkey::new_unique();
 
     context
@@ -58,6 +78,7 @@ async fn fail_soulbound_token_account_has_tokens() {
     context
         .expect_error(
             &[order_tracker::instruction::initialize_profile(
+                &DEFAULT_NAMESPACE,
                 &wallet.pubkey(),
             )],
             &[&wallet],
@@ -73,7 +94,7 @@ async fn fail_profile_exists() {
     let wallet = setup_wallet_with_soulbound_token_account(&mut context);
 
     context.set_account(
-        &Profile::address(&wallet.pubkey()),
+        &Profile::address(&DEFAULT_NAMESPACE, &wallet.pubkey()),
         &Account {
             lamports: 1_000_000_000,
             owner: order_tracker::id(),
@@ -85,6 +106,7 @@ async fn fail_profile_exists() {
     context
         .expect_error(
             &[order_tracker::instruction::initialize_profile(
+                &DEFAULT_NAMESPACE,
                 &wallet.pubkey(),
             )],
             &[&wallet],
@@ -93,13 +115,52 @@ async fn fail_profile_exists() {
         .await;
 }
 
+#[tokio::test]
+async fn fail_wallet_linked() {
+    let mut context = setup().await;
+
+    let wallet = setup_wallet_with_soulbound_token_account(&mut context);
+    set_wallet_link(&mut context, &wallet.pubkey(), &Pubkey::new_unique());
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::initialize_profile(
+                &DEFAULT_NAMESPACE,
+                &wallet.pubkey(),
+            )],
+            &[&wallet],
+            (0, ProtocolError::WalletAlreadyLinked),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_wallet_linked_to_itself() {
+    let mut context = setup().await;
+
+    // Exemptions used to link exempt owners to themselves.
+    let wallet = setup_wallet_with_soulbound_token_account(&mut context);
+    set_wallet_link(&mut context, &wallet.pubkey(), &wallet.pubkey());
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::initialize_profile(
+                &DEFAULT_NAMESPACE,
+                &wallet.pubkey(),
+            )],
+            &[&wallet],
+        )
+        .await;
+}
+
 #[tokio::test]
 async fn fail_wallet_not_signer() {
     let mut context = setup().await;
 
     let wallet = setup_wallet_with_soulbound_token_account(&mut context);
 
-    let mut instruction = order_tracker::instruction::initialize_profile(&wallet.pubkey());
+    let mut instruction =
+        order_tracker::instruction::initialize_profile(&DEFAULT_NAMESPACE, &wallet.pubkey());
     instruction.accounts[3].is_signer = false;
 
     context
@@ -117,12 +178,20 @@ async fn success() {
 
     let wallet = setup_wallet_with_soulbound_token_account(&mut context);
 
-    context
-        .expect_success(
+    let events = context
+        .expect_events(
             &[order_tracker::instruction::initialize_profile(
+                &DEFAULT_NAMESPACE,
                 &wallet.pubkey(),
             )],
             &[&wallet],
         )
         .await;
+    assert_eq!(
+        events,
+        vec![ProtocolEvent::ProfileInitialized {
+            namespace: DEFAULT_NAMESPACE,
+            wallet: wallet.pubkey(),
+        }]
+    );
 }
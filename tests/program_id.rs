@@ -0,0 +1,151 @@
+#![cfg(feature = "test-sbf")]
+mod context;
+
+use {
+    borsh::BorshDeserialize,
+    context::{
+        setup_empty_protocol_mint_account, setup_empty_protocol_validation_account_with_program_id,
+        setup_soulbound_token_account_with_program_id, setup_wallet, setup_with_program_id,
+        ProtocolTestContext,
+    },
+    order_tracker::{
+        error::ProtocolError,
+        state::{MintRules, Profile, ProtocolConfig, Soulbound, DEFAULT_NAMESPACE},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{pubkey::Pubkey, signer::Signer},
+};
+
+const DECIMALS: u8 = 0;
+const NAME: &str = "Joe Token";
+const SYMBOL: &str = "JOE";
+const URI: &str = "https://www.joetoken.com";
+
+#[tokio::test]
+async fn fail_address_derived_from_other_program_id() {
+    let program_id = Pubkey::new_unique();
+    let mut context = setup_with_program_id(&program_id).await;
+
+    let wallet = setup_wallet(&mut context);
+    setup_soulbound_token_account_with_program_id(
+        &mut context,
+        &DEFAULT_NAMESPACE,
+        &wallet.pubkey(),
+        0,
+        &program_id,
+    );
+
+    let mut instruction = order_tracker::instruction::initialize_profile_with_program_id(
+        &DEFAULT_NAMESPACE,
+        &wallet.pubkey(),
+        &program_id,
+    );
+    instruction.accounts[0].pubkey = Soulbound::address(&DEFAULT_NAMESPACE);
+
+    context
+        .expect_error(
+            &[instruction],
+            &[&wallet],
+            (0, ProtocolError::IncorrectSoulboundMint),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_deployed_under_program_id() {
+    let program_id = Pubkey::new_unique();
+    let mut context = setup_with_program_id(&program_id).await;
+
+    // The protocol is initialized at addresses derived from the program ID.
+    let config_address = ProtocolConfig::address_with_program_id(&DEFAULT_NAMESPACE, &program_id);
+    let account = context
+        .banks_client
+        .get_account(config_address)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.owner, program_id);
+    assert!(context
+        .banks_client
+        .get_account(ProtocolConfig::address(&DEFAULT_NAMESPACE))
+        .await
+        .unwrap()
+        .is_none());
+
+    // Initialize a profile.
+    let wallet = setup_wallet(&mut context);
+    setup_soulbound_token_account_with_program_id(
+        &mut context,
+        &DEFAULT_NAMESPACE,
+        &wallet.pubkey(),
+        0,
+        &program_id,
+    );
+    context
+        .expect_success(
+            &[
+                order_tracker::instruction::initialize_profile_with_program_id(
+                    &DEFAULT_NAMESPACE,
+                    &wallet.pubkey(),
+                    &program_id,
+                ),
+            ],
+            &[&wallet],
+        )
+        .await;
+
+    let account = context
+        .banks_client
+        .get_account(Profile::address_with_program_id(
+            &DEFAULT_NAMESPACE,
+            &wallet.pubkey(),
+            &program_id,
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.owner, program_id);
+    let profile = Profile::try_from_slice(&account.data).unwrap();
+    assert_eq!(profile.wallet_address, wallet.pubkey());
+
+    // Create a mint, credentialed by the deployment's soulbound mint.
+    let mint_authority = setup_wallet(&mut context);
+    let mint = setup_empty_protocol_mint_account(&mut context);
+    setup_empty_protocol_validation_account_with_program_id(
+        &mut context,
+        &mint.pubkey(),
+        &program_id,
+    );
+    let rules = MintRules {
+        credential_mint: Soulbound::address_with_program_id(&DEFAULT_NAMESPACE, &program_id),
+        ..MintRules::default()
+    };
+    context
+        .expect_success(
+            &[order_tracker::instruction::create_mint_with_program_id(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &context.payer.pubkey(),
+                DECIMALS,
+                NAME,
+                SYMBOL,
+                URI,
+                &rules,
+                &program_id,
+            )],
+            &[&mint_authority],
+        )
+        .await;
+
+    let account = context
+        .banks_client
+        .get_account(MintRules::address_with_program_id(
+            &mint.pubkey(),
+            &program_id,
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.owner, program_id);
+    assert_eq!(MintRules::try_from_slice(&account.data).unwrap(), rules);
+}
@@ -0,0 +1,223 @@
+#![cfg(feature = "test-sbf")]
+mod context;
+
+use {
+    borsh::BorshDeserialize,
+    context::{
+        get_wallet_link, setup, setup_profile, setup_wallet,
+        setup_wallet_with_soulbound_token_account, ProtocolTestContext,
+    },
+    order_tracker::{
+        error::ProtocolError,
+        state::{Profile, Soulbound, WalletLink, DEFAULT_NAMESPACE},
+    },
+    solana_program_test::{tokio, ProgramTestContext},
+    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+    spl_token_2022::{extension::StateWithExtensions, state::Account as TokenAccount},
+};
+
+async fn get_soulbound_balance(context: &mut ProgramTestContext, wallet_address: &Pubkey) -> u64 {
+    let account = context
+        .banks_client
+        .get_account(Soulbound::token_account(&DEFAULT_NAMESPACE, wallet_address))
+        .await
+        .unwrap()
+        .unwrap();
+    StateWithExtensions::<TokenAccount>::unpack(&account.data)
+        .unwrap()
+        .base
+        .amount
+}
+
+#[tokio::test]
+async fn fail_incorrect_admin() {
+    let mut context = setup().await;
+
+    let old_wallet = setup_profile(&mut context).await;
+    let new_wallet = setup_wallet_with_soulbound_token_account(&mut context);
+    let not_admin = setup_wallet(&mut context);
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::recover_profile(
+                &DEFAULT_NAMESPACE,
+                &not_admin.pubkey(),
+                &old_wallet.pubkey(),
+                &new_wallet.pubkey(),
+                &[],
+            )],
+            &[&not_admin, &new_wallet],
+            (0, ProtocolError::IncorrectAdmin),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_new_wallet_has_profile() {
+    let mut context = setup().await;
+
+    let admin = context.payer.pubkey();
+    let old_wallet = setup_profile(&mut context).await;
+    let new_wallet = setup_profile(&mut context).await;
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::recover_profile(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &old_wallet.pubkey(),
+                &new_wallet.pubkey(),
+                &[],
+            )],
+            &[&new_wallet],
+            (0, ProtocolError::ProfileAlreadyInitialized),
+        )
+        .await;
+}
+
+async fn setup_linked_wallet(context: &mut ProgramTestContext, primary: &Keypair) -> Keypair {
+    let secondary = setup_wallet(context);
+    context
+        .expect_success(
+            &[order_tracker::instruction::link_wallet(
+                &DEFAULT_NAMESPACE,
+                &primary.pubkey(),
+                &secondary.pubkey(),
+            )],
+            &[primary, &secondary],
+        )
+        .await;
+    secondary
+}
+
+#[tokio::test]
+async fn fail_linked_wallet_missing() {
+    let mut context = setup().await;
+
+    let admin = context.payer.pubkey();
+    let old_wallet = setup_profile(&mut context).await;
+    let first_secondary = setup_linked_wallet(&mut context, &old_wallet).await;
+    setup_linked_wallet(&mut context, &old_wallet).await;
+    let new_wallet = setup_wallet_with_soulbound_token_account(&mut context);
+
+    // Passing the same link twice doesn't make up for the missing one.
+    context
+        .expect_error(
+            &[order_tracker::instruction::recover_profile(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &old_wallet.pubkey(),
+                &new_wallet.pubkey(),
+                &[first_secondary.pubkey()],
+            )],
+            &[&new_wallet],
+            (0, ProtocolError::LinkedWalletsMissing),
+        )
+        .await;
+    context
+        .expect_error(
+            &[order_tracker::instruction::recover_profile(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &old_wallet.pubkey(),
+                &new_wallet.pubkey(),
+                &[first_secondary.pubkey(), first_secondary.pubkey()],
+            )],
+            &[&new_wallet],
+            (0, ProtocolError::IncorrectWalletLink),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_linked_wallets_moved() {
+    let mut context = setup().await;
+
+    let admin = context.payer.pubkey();
+    let old_wallet = setup_profile(&mut context).await;
+    let secondary = setup_linked_wallet(&mut context, &old_wallet).await;
+    let new_wallet = setup_wallet_with_soulbound_token_account(&mut context);
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::recover_profile(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &old_wallet.pubkey(),
+                &new_wallet.pubkey(),
+                &[secondary.pubkey()],
+            )],
+            &[&new_wallet],
+        )
+        .await;
+
+    let link = get_wallet_link(&mut context, &secondary.pubkey()).await;
+    assert_eq!(link.primary_wallet, new_wallet.pubkey());
+}
+
+#[tokio::test]
+async fn success() {
+    let mut context = setup().await;
+
+    let admin = context.payer.pubkey();
+    let old_wallet = setup_profile(&mut context).await;
+    let new_wallet = setup_wallet_with_soulbound_token_account(&mut context);
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::recover_profile(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &old_wallet.pubkey(),
+                &new_wallet.pubkey(),
+                &[],
+            )],
+            &[&new_wallet],
+        )
+        .await;
+
+    // The profile now belongs to the new wallet.
+    let account = context
+        .banks_client
+        .get_account(Profile::address(&DEFAULT_NAMESPACE, &new_wallet.pubkey()))
+        .await
+        .unwrap()
+        .unwrap();
+    let profile = Profile::try_from_slice(&account.data).unwrap();
+    assert_eq!(profile.wallet_address, new_wallet.pubkey());
+
+    let account = context
+        .banks_client
+        .get_account(WalletLink::address(
+            &DEFAULT_NAMESPACE,
+            &new_wallet.pubkey(),
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    let link = WalletLink::try_from_slice(&account.data).unwrap();
+    assert_eq!(link.primary_wallet, new_wallet.pubkey());
+
+    // The old profile and wallet link are closed.
+    for address in [
+        Profile::address(&DEFAULT_NAMESPACE, &old_wallet.pubkey()),
+        WalletLink::address(&DEFAULT_NAMESPACE, &old_wallet.pubkey()),
+    ] {
+        assert!(context
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    // The soulbound token moved from the old wallet to the new wallet.
+    assert_eq!(
+        get_soulbound_balance(&mut context, &old_wallet.pubkey()).await,
+        0
+    );
+    assert_eq!(
+        get_soulbound_balance(&mut context, &new_wallet.pubkey()).await,
+        1
+    );
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "test-sbf")]
+mod context;
+
+use {
+    borsh::BorshDeserialize,
+    context::{
+        setup, setup_empty_protocol_mint_account, setup_empty_protocol_validation_account,
+        setup_wallet, ProtocolTestContext,
+    },
+    order_tracker::{error::ProtocolError, state::MintRules},
+    solana_program_test::{tokio, ProgramTestContext},
+    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+const DECIMALS: u8 = 0;
+const NAME: &str = "Joe Token";
+const SYMBOL: &str = "JOE";
+const URI: &str = "https://www.joetoken.com";
+
+async fn setup_mint(context: &mut ProgramTestContext, rules: &MintRules) -> (Keypair, Keypair) {
+    let mint_authority = setup_wallet(context);
+    let mint = setup_empty_protocol_mint_account(context);
+    setup_empty_protocol_validation_account(context, &mint.pubkey());
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::create_mint(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &context.payer.pubkey(),
+                DECIMALS,
+                NAME,
+                SYMBOL,
+                URI,
+                rules,
+            )],
+            &[&mint_authority],
+        )
+        .await;
+
+    (mint, mint_authority)
+}
+
+async fn get_mint_rules(context: &mut ProgramTestContext, mint_address: &Pubkey) -> MintRules {
+    let account = context
+        .banks_client
+        .get_account(MintRules::address(mint_address))
+        .await
+        .unwrap()
+        .unwrap();
+    MintRules::try_from_slice(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn fail_incorrect_mint_authority() {
+    let mut context = setup().await;
+
+    let (mint, _) = setup_mint(&mut context, &MintRules::default()).await;
+    let not_mint_authority = setup_wallet(&mut context);
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::update_mint_rules(
+                &mint.pubkey(),
+                &not_mint_authority.pubkey(),
+                &MintRules::default(),
+            )],
+            &[&not_mint_authority],
+            (0, ProtocolError::IncorrectMintAuthority),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_namespace_changed() {
+    let mut context = setup().await;
+
+    let (mint, mint_authority) = setup_mint(&mut context, &MintRules::default()).await;
+
+    let rules = MintRules {
+        namespace: Pubkey::new_unique(),
+        ..MintRules::default()
+    };
+    context
+        .expect_error(
+            &[order_tracker::instruction::update_mint_rules(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &rules,
+            )],
+            &[&mint_authority],
+            (0, ProtocolError::IncorrectNamespace),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_create_and_update() {
+    let mut context = setup().await;
+
+    let rules = MintRules {
+        require_source_credential: false,
+        ..MintRules::default()
+    };
+    let (mint, mint_authority) = setup_mint(&mut context, &rules).await;
+    assert_eq!(get_mint_rules(&mut context, &mint.pubkey()).await, rules);
+
+    let rules = MintRules {
+        track_volume: false,
+        ..MintRules::default()
+    };
+    context
+        .expect_success(
+            &[order_tracker::instruction::update_mint_rules(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &rules,
+            )],
+            &[&mint_authority],
+        )
+        .await;
+    assert_eq!(get_mint_rules(&mut context, &mint.pubkey()).await, rules);
+}
@@ -0,0 +1,147 @@
+#![cfg(feature = "test-sbf")]
+mod context;
+
+use {
+    borsh::BorshDeserialize,
+    context::{
+        setup, setup_empty_protocol_mint_account, setup_empty_protocol_validation_account,
+        setup_wallet, ProtocolTestContext,
+    },
+    order_tracker::{
+        error::ProtocolError,
+        state::{MintRules, TradingOverride, TradingSchedule, TradingWindow, DEFAULT_NAMESPACE},
+    },
+    solana_program_test::{tokio, ProgramTestContext},
+    solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer},
+};
+
+const DECIMALS: u8 = 0;
+const NAME: &str = "Joe Token";
+const SYMBOL: &str = "JOE";
+const URI: &str = "https://www.joetoken.com";
+
+async fn setup_mint(context: &mut ProgramTestContext) -> (Keypair, Keypair) {
+    let mint_authority = setup_wallet(context);
+    let mint = setup_empty_protocol_mint_account(context);
+    setup_empty_protocol_validation_account(context, &mint.pubkey());
+
+    context
+        .expect_success(
+            &[order_tracker::instruction::create_mint(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &context.payer.pubkey(),
+                DECIMALS,
+                NAME,
+                SYMBOL,
+                URI,
+                &MintRules::default(),
+            )],
+            &[&mint_authority],
+        )
+        .await;
+
+    (mint, mint_authority)
+}
+
+async fn get_trading_schedule(
+    context: &mut ProgramTestContext,
+    mint_address: &Pubkey,
+) -> TradingSchedule {
+    let account = context
+        .banks_client
+        .get_account(TradingSchedule::address(mint_address))
+        .await
+        .unwrap()
+        .unwrap();
+    TradingSchedule::try_from_slice(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn fail_incorrect_mint_authority() {
+    let mut context = setup().await;
+
+    let (mint, _) = setup_mint(&mut context).await;
+    let not_mint_authority = setup_wallet(&mut context);
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::set_trading_windows(
+                &mint.pubkey(),
+                &not_mint_authority.pubkey(),
+                &[],
+            )],
+            &[&not_mint_authority],
+            (0, ProtocolError::IncorrectMintAuthority),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn fail_incorrect_admin() {
+    let mut context = setup().await;
+
+    let (mint, _) = setup_mint(&mut context).await;
+    let not_admin = setup_wallet(&mut context);
+
+    context
+        .expect_error(
+            &[order_tracker::instruction::set_trading_override(
+                &DEFAULT_NAMESPACE,
+                &not_admin.pubkey(),
+                &mint.pubkey(),
+                TradingOverride::Closed,
+            )],
+            &[&not_admin],
+            (0, ProtocolError::IncorrectAdmin),
+        )
+        .await;
+}
+
+#[tokio::test]
+async fn success_set_windows_and_override() {
+    let mut context = setup().await;
+
+    let admin = context.payer.pubkey();
+    let (mint, mint_authority) = setup_mint(&mut context).await;
+
+    // The admin override can be set before the mint authority sets windows.
+    context
+        .expect_success(
+            &[order_tracker::instruction::set_trading_override(
+                &DEFAULT_NAMESPACE,
+                &admin,
+                &mint.pubkey(),
+                TradingOverride::Closed,
+            )],
+            &[],
+        )
+        .await;
+
+    let windows = vec![
+        TradingWindow {
+            days: 0b0111110,
+            open_second: 9 * 60 * 60,
+            close_second: 17 * 60 * 60,
+        },
+        TradingWindow {
+            days: 0b1000001,
+            open_second: 10 * 60 * 60,
+            close_second: 12 * 60 * 60,
+        },
+    ];
+    context
+        .expect_success(
+            &[order_tracker::instruction::set_trading_windows(
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                &windows,
+            )],
+            &[&mint_authority],
+        )
+        .await;
+
+    let schedule = get_trading_schedule(&mut context, &mint.pubkey()).await;
+    assert_eq!(schedule.trading_override, TradingOverride::Closed);
+    assert_eq!(schedule.windows, windows);
+}